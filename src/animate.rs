@@ -0,0 +1,211 @@
+use std::{
+    collections::HashSet,
+    io::Write,
+    sync::mpsc::{Receiver, RecvTimeoutError},
+    time::Duration,
+};
+
+use crate::grid::Grid;
+
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+const CLEAR_TO_END_OF_LINE: &str = "\x1b[K";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+const HIGHLIGHT: &str = "\x1b[1;33;41m";
+const RESET: &str = "\x1b[0m";
+
+/// One tick of a simulation: the grid as text, the cells to highlight (such as
+/// the guard or robot that just moved) and a status line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub highlight: HashSet<(usize, usize)>,
+    pub status: String,
+}
+
+/// A day's simulation, one frame per tick.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+impl Frame {
+    pub fn new(
+        grid: Grid<char>,
+        highlight: impl IntoIterator<Item = (usize, usize)>,
+        status: impl Into<String>,
+    ) -> Self {
+        Frame {
+            grid,
+            highlight: highlight.into_iter().collect(),
+            status: status.into(),
+        }
+    }
+
+    /// Draws the frame over the previous one, starting from the top-left corner.
+    pub fn to_ansi(&self, paused: bool) -> String {
+        let mut res = String::from(CURSOR_HOME);
+
+        for (row, cells) in self.grid.rows().enumerate() {
+            for (col, &ch) in cells.iter().enumerate() {
+                if self.highlight.contains(&(row, col)) {
+                    res += HIGHLIGHT;
+                    res.push(ch);
+                    res += RESET;
+                } else {
+                    res.push(ch);
+                }
+            }
+            res += CLEAR_TO_END_OF_LINE;
+            res.push('\n');
+        }
+
+        res += &self.status;
+        if paused {
+            res += "  [paused: Enter steps, p resumes, q quits]";
+        }
+        res += CLEAR_TO_END_OF_LINE;
+        res.push('\n');
+
+        res
+    }
+}
+
+/// Commands read from the terminal while an animation plays, one per line
+/// since stdin is line-buffered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    Step,
+    Quit,
+}
+
+impl Control {
+    /// `p` toggles pause, `q` quits and an empty line steps one frame.
+    pub fn from_line(line: &str) -> Option<Self> {
+        match line.trim() {
+            "" => Some(Control::Step),
+            "p" | "P" => Some(Control::TogglePause),
+            "q" | "Q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Plays `frames` to `out` at `fps`, reacting to `controls` between frames.
+/// Stepping while playing skips straight to the next frame. When the controls
+/// disconnect (say stdin is closed) playback carries on without them.
+pub fn play<W>(
+    frames: impl Iterator<Item = Frame>,
+    fps: f64,
+    start_paused: bool,
+    controls: &Receiver<Control>,
+    out: &mut W,
+) -> std::io::Result<()>
+where
+    W: Write,
+{
+    let frame_time = Duration::from_secs_f64(1.0 / fps);
+    let mut paused = start_paused;
+    let mut controls_open = true;
+
+    write!(out, "{}{}", HIDE_CURSOR, CLEAR_SCREEN)?;
+
+    let mut frames = frames.peekable();
+    while let Some(frame) = frames.next() {
+        write!(out, "{}", frame.to_ansi(paused))?;
+        out.flush()?;
+
+        if frames.peek().is_none() {
+            break;
+        }
+
+        // Wait until it's time for the next frame, handling controls meanwhile
+        loop {
+            let control = if !controls_open {
+                std::thread::sleep(frame_time);
+                break;
+            } else if paused {
+                controls.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                controls.recv_timeout(frame_time)
+            };
+
+            match control {
+                Ok(Control::Step) => break,
+                Ok(Control::Quit) => {
+                    write!(out, "{}", SHOW_CURSOR)?;
+                    return Ok(());
+                }
+                Ok(Control::TogglePause) => {
+                    paused = !paused;
+                    write!(out, "{}", frame.to_ansi(paused))?;
+                    out.flush()?;
+                }
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => {
+                    controls_open = false;
+                    paused = false;
+                }
+            }
+        }
+    }
+
+    write!(out, "{}", SHOW_CURSOR)?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    fn frames(n: usize) -> impl Iterator<Item = Frame> {
+        (0..n).map(|i| Frame::new("...".parse().unwrap(), [(0, i % 3)], format!("step {}", i)))
+    }
+
+    #[test]
+    fn test_to_ansi_highlights() {
+        let frame = Frame::new("#.@".parse().unwrap(), [(0, 2)], "step 1");
+
+        assert_eq!(
+            frame.to_ansi(false),
+            "\x1b[H#.\x1b[1;33;41m@\x1b[0m\x1b[K\nstep 1\x1b[K\n"
+        );
+        assert!(frame.to_ansi(true).contains("[paused"));
+    }
+
+    #[test]
+    fn test_control_from_line() {
+        assert_eq!(Control::from_line("\n"), Some(Control::Step));
+        assert_eq!(Control::from_line("p\n"), Some(Control::TogglePause));
+        assert_eq!(Control::from_line("q"), Some(Control::Quit));
+        assert_eq!(Control::from_line("x"), None);
+    }
+
+    #[test]
+    fn test_play_without_controls() {
+        let (tx, rx) = mpsc::channel();
+        drop(tx);
+        let mut out = vec![];
+
+        play(frames(3), 1000.0, false, &rx, &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches(CURSOR_HOME).count(), 3);
+        assert!(out.ends_with(SHOW_CURSOR));
+    }
+
+    #[test]
+    fn test_play_paused_steps_then_quits() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(Control::Step).unwrap();
+        tx.send(Control::Quit).unwrap();
+        let mut out = vec![];
+
+        play(frames(10), 1000.0, true, &rx, &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("step 1"));
+        assert!(!out.contains("step 2"));
+    }
+}
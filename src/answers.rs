@@ -0,0 +1,312 @@
+use std::{collections::BTreeMap, fmt::Display, path::Path, str::FromStr};
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use crate::{parse::ParseError, solution::Part};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers keyed by day and part, stored as a small TOML file:
+///
+/// ```toml
+/// [day1]
+/// part_one = "11"
+/// part_two = "31"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// Loads answers from `path`, treating a missing file as having none recorded.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        contents
+            .parse()
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.entries.get(&(day, part.number())).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        self.entries.insert((day, part.number()), answer.into());
+    }
+
+    /// Every day with at least one recorded answer, in order.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.entries.keys().map(|&(day, _)| day).dedup()
+    }
+
+    pub fn check(&self, day: u8, part: Part, actual: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == actual => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unrecorded,
+        }
+    }
+}
+
+fn part_key(part: u8) -> &'static str {
+    if part == 1 {
+        "part_one"
+    } else {
+        "part_two"
+    }
+}
+
+/// Quotes `answer` as a TOML basic string.
+fn quote(answer: &str) -> String {
+    let mut res = String::from('"');
+
+    for ch in answer.chars() {
+        match ch {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            '\t' => res += "\\t",
+            '\r' => res += "\\r",
+            ch if ch.is_control() => res += &format!("\\u{:04X}", ch as u32),
+            ch => res.push(ch),
+        }
+    }
+
+    res.push('"');
+    res
+}
+
+/// Parses a value that is either a TOML basic string or a bare word, with an
+/// optional `# comment` after it.
+fn parse_value(value: &str) -> Result<String, &'static str> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        let bare = value.split('#').next().unwrap_or_default().trim();
+        return Ok(bare.to_string());
+    };
+
+    let mut answer = String::new();
+    let mut chars = quoted.chars();
+
+    loop {
+        match chars.next().ok_or("unterminated string")? {
+            '"' => break,
+            '\\' => {
+                let escaped = match chars.next().ok_or("unterminated string")? {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    escape @ ('u' | 'U') => {
+                        let len = if escape == 'u' { 4 } else { 8 };
+                        let hex = chars.by_ref().take(len).collect::<String>();
+
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .filter(|_| hex.len() == len)
+                            .and_then(char::from_u32)
+                            .ok_or("invalid unicode escape")?
+                    }
+                    _ => return Err("invalid escape"),
+                };
+
+                answer.push(escaped);
+            }
+            ch => answer.push(ch),
+        }
+    }
+
+    let rest = chars.as_str().trim();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err("unexpected text after string");
+    }
+
+    Ok(answer)
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line_no = i + 1;
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = trimmed.strip_prefix('[') {
+                let number = table
+                    .strip_suffix(']')
+                    .and_then(|t| t.strip_prefix("day"))
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .ok_or_else(|| ParseError::new(line_no, 1, trimmed, "expected [dayN]"))?;
+
+                day = Some(number);
+                continue;
+            }
+
+            let (key, value) = trimmed
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(line_no, 1, trimmed, "expected key = value"))?;
+
+            let part = match key.trim() {
+                "part_one" => 1,
+                "part_two" => 2,
+                other => {
+                    return Err(ParseError::new(
+                        line_no,
+                        1,
+                        other,
+                        "expected part_one or part_two",
+                    ))
+                }
+            };
+
+            let value = value.trim();
+            let answer =
+                parse_value(value).map_err(|msg| ParseError::new(line_no, 1, value, msg))?;
+
+            let day = day.ok_or_else(|| {
+                ParseError::new(line_no, 1, trimmed, "expected a [dayN] table first")
+            })?;
+
+            answers.entries.insert((day, part), answer);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut current_day = None;
+
+        for (&(day, part), answer) in &self.entries {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{}]", day)?;
+                current_day = Some(day);
+            }
+
+            writeln!(f, "{} = {}", part_key(part), quote(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Unrecorded,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Match => f.write_str("ok"),
+            Verdict::Mismatch { expected } => write!(f, "MISMATCH (expected {})", expected),
+            Verdict::Unrecorded => f.write_str("unrecorded"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(10, Part::Two, "81");
+        answers.insert(1, Part::One, "11");
+        answers.insert(1, Part::Two, "31");
+
+        let toml = answers.to_string();
+
+        assert_eq!(
+            toml,
+            "[day1]\npart_one = \"11\"\npart_two = \"31\"\n\n[day10]\npart_two = \"81\"\n"
+        );
+        assert_eq!(toml.parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn test_round_trip_escapes() {
+        let mut answers = Answers::default();
+        answers.insert(1, Part::One, r#"say "hi" \ # not a comment"#);
+        answers.insert(1, Part::Two, "tab\there\nline\u{1b}");
+
+        let toml = answers.to_string();
+
+        assert_eq!(
+            toml,
+            "[day1]\n\
+             part_one = \"say \\\"hi\\\" \\\\ # not a comment\"\n\
+             part_two = \"tab\\there\\nline\\u001B\"\n"
+        );
+        assert_eq!(toml.parse::<Answers>().unwrap(), answers);
+    }
+
+    #[test]
+    fn test_parse_bare_values_and_comments() {
+        let answers = "# accepted answers\n[day3]\npart_one = 161 # from the site\n\
+                       part_two = \"48\" # quoted\n"
+            .parse::<Answers>()
+            .unwrap();
+
+        assert_eq!(answers.get(3, Part::One), Some("161"));
+        assert_eq!(answers.get(3, Part::Two), Some("48"));
+        assert!("[day3]\npart_one = \"161".parse::<Answers>().is_err());
+        assert!("[day3]\npart_one = \"16\"1".parse::<Answers>().is_err());
+        assert!("[day3]\npart_one = \"\\q\"".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = "[day3]\npart_three = 1".parse::<Answers>().unwrap_err();
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.text, "part_three");
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::default();
+        answers.insert(9, Part::One, "1928");
+
+        assert_eq!(answers.check(9, Part::One, "1928"), Verdict::Match);
+        assert_eq!(
+            answers.check(9, Part::One, "1929"),
+            Verdict::Mismatch {
+                expected: "1928".to_string()
+            }
+        );
+        assert_eq!(answers.check(9, Part::Two, "2858"), Verdict::Unrecorded);
+    }
+}
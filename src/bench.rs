@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use crate::{days::Day, runner::format_duration};
+
+/// Summary statistics over the samples collected for a single phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        // Nearest-rank percentile
+        let p95_rank = (n * 95).div_ceil(100);
+
+        Stats {
+            min: sorted[0],
+            median,
+            p95: sorted[p95_rank - 1],
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            r#"{{"min_ns": {}, "median_ns": {}, "p95_ns": {}}}"#,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.p95.as_nanos()
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchResult {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> anyhow::Result<BenchResult> {
+    assert!(iterations > 0, "Need at least one iteration");
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part_one = Vec::with_capacity(iterations);
+    let mut part_two = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let timings = day.solution.time_phases(input)?;
+
+        parse.push(timings.parse);
+        part_one.push(timings.part_one);
+        part_two.push(timings.part_two);
+    }
+
+    Ok(BenchResult {
+        day: day.number,
+        iterations,
+        parse: Stats::from_samples(&parse),
+        part_one: Stats::from_samples(&part_one),
+        part_two: Stats::from_samples(&part_two),
+    })
+}
+
+pub fn results_table(results: &[BenchResult]) -> String {
+    let mut res = format!(
+        "{:>3}  {:<8}  {:>10}  {:>10}  {:>10}\n",
+        "Day", "Phase", "Min", "Median", "p95"
+    );
+    res += &format!("{}\n", "-".repeat(3 + 2 + 8 + 3 * (2 + 10)));
+
+    for r in results {
+        for (phase, stats) in [
+            ("parse", r.parse),
+            ("part one", r.part_one),
+            ("part two", r.part_two),
+        ] {
+            res += &format!(
+                "{:>3}  {:<8}  {:>10}  {:>10}  {:>10}\n",
+                r.day,
+                phase,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.p95)
+            );
+        }
+    }
+
+    res
+}
+
+/// Renders results as JSON, with every duration in nanoseconds so runs from
+/// different commits can be diffed directly.
+pub fn results_json(results: &[BenchResult]) -> String {
+    let days = results
+        .iter()
+        .map(|r| {
+            format!(
+                r#"    {{"day": {}, "iterations": {}, "parse": {}, "part_one": {}, "part_two": {}}}"#,
+                r.day,
+                r.iterations,
+                r.parse.to_json(),
+                r.part_one.to_json(),
+                r.part_two.to_json()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("{{\n  \"days\": [\n{}\n  ]\n}}\n", days.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(ms).collect::<Vec<_>>();
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, ms(19));
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[ms(7)]);
+
+        assert_eq!(stats.min, ms(7));
+        assert_eq!(stats.median, ms(7));
+        assert_eq!(stats.p95, ms(7));
+    }
+
+    #[test]
+    fn test_results_json() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(5)]);
+        let results = [BenchResult {
+            day: 3,
+            iterations: 1,
+            parse: stats,
+            part_one: stats,
+            part_two: stats,
+        }];
+
+        let stats_json = r#"{"min_ns": 5, "median_ns": 5, "p95_ns": 5}"#;
+        assert_eq!(
+            results_json(&results),
+            format!(
+                "{{\n  \"days\": [\n    {{\"day\": 3, \"iterations\": 1, \"parse\": {0}, \"part_one\": {0}, \"part_two\": {0}}}\n  ]\n}}\n",
+                stats_json
+            )
+        );
+    }
+}
@@ -0,0 +1,457 @@
+use std::{io::BufRead, path::PathBuf, sync::mpsc};
+
+use anyhow::{bail, Context};
+use aoc_2024::{
+    animate::{self, Control},
+    answers::{self, Answers, Verdict},
+    bench,
+    days::{self, Day, DAYS},
+    image::{Image, Palette},
+    input::{InputSource, MissingInput},
+    runner, scaffold,
+    solution::{Part, Unsolved},
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Run Advent of Code 2024 solutions.
+#[derive(Debug, Parser)]
+#[command(name = "aoc", args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve puzzles and print a summary of answers and timings (the default)
+    Run(RunArgs),
+
+    /// Time each day's parse, part one and part two over many iterations
+    Bench(BenchArgs),
+
+    /// Rerun solvers against their real inputs and compare with recorded answers
+    Verify(VerifyArgs),
+
+    /// Draw each day's puzzle to an image file
+    Render(RenderArgs),
+
+    /// Break a day's answers down item by item, such as why each report is unsafe
+    Explain(ExplainArgs),
+
+    /// Scaffold a new day's solution module, example fixtures and registry entry
+    New {
+        /// Day to create (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Debug, Args)]
+struct Selection {
+    /// Day to run (1-25)
+    #[arg(short, long, conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Run every registered day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Read the puzzle input from this file instead, or from stdin when `-`
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Directory containing `dayN.txt` inputs [env: AOC_INPUTS_DIR, default: inputs]
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
+}
+
+impl Selection {
+    fn days(&self) -> anyhow::Result<Vec<&'static Day>> {
+        match (self.day, self.all) {
+            (Some(number), false) => match days::get(number) {
+                Some(day) => Ok(vec![day]),
+                None => bail!("Day {} has no registered solution", number),
+            },
+            (None, true) => Ok(DAYS.iter().collect()),
+            _ => bail!("Either --day or --all must be given"),
+        }
+    }
+
+    fn days_or_all(&self) -> anyhow::Result<Vec<&'static Day>> {
+        if self.day.is_none() {
+            Ok(DAYS.iter().collect())
+        } else {
+            self.days()
+        }
+    }
+
+    fn source(&self) -> InputSource {
+        InputSource::resolve(self.input.as_deref(), self.inputs_dir.as_deref())
+    }
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Part to run (1 or 2); runs both parts when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Play the day's simulation in the terminal instead of printing answers.
+    /// While playing, Enter steps one frame, `p` pauses or resumes and `q` quits
+    #[arg(long, requires = "day", conflicts_with = "format")]
+    animate: bool,
+
+    /// Frames per second when animating
+    #[arg(long, default_value_t = 10.0, requires = "animate")]
+    fps: f64,
+
+    /// Start the animation paused, so it can be stepped frame by frame
+    #[arg(long, requires = "animate")]
+    paused: bool,
+
+    /// Solve both parts in a single pass over the input without reading it all
+    /// into memory, for days that support it
+    #[arg(long, requires = "day", conflicts_with_all = ["animate", "part"])]
+    stream: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    /// Aligned table with a total time
+    Table,
+    /// One JSON object per line
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of times to run each phase
+    #[arg(short = 'n', long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+
+    /// Where to write the machine-readable results
+    #[arg(long, default_value = "bench.json")]
+    json: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Days to verify, every registered day when omitted
+    #[command(flatten)]
+    selection: Selection,
+
+    /// File of accepted answers
+    #[arg(long, default_value = answers::DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+
+    /// Record answers for parts that have none yet; mismatches are never overwritten
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Debug, Args)]
+struct RenderArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Directory to write `dayN.png` (or `.ppm`) images to
+    #[arg(long, default_value = ".")]
+    out_dir: PathBuf,
+
+    /// Image format to write
+    #[arg(long, value_enum, default_value_t = ImageFormat::Png)]
+    image_format: ImageFormat,
+
+    /// Width and height of each grid cell, in pixels
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+    cell_size: u32,
+
+    /// Comma-separated `#rrggbb` colors, background first
+    #[arg(long)]
+    palette: Option<Palette>,
+}
+
+#[derive(Debug, Args)]
+struct ExplainArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// How to print the breakdown
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::Run(args)) => run(&args),
+        Some(Command::Bench(args)) => run_bench(&args),
+        Some(Command::Verify(args)) => verify(&args),
+        Some(Command::Render(args)) => render(&args),
+        Some(Command::Explain(args)) => explain(&args),
+        Some(Command::New { day }) => new_day(day),
+        None => run(&cli.run),
+    }
+}
+
+fn run(args: &RunArgs) -> anyhow::Result<()> {
+    if args.animate {
+        return run_animation(args);
+    }
+
+    let parts = match args.part {
+        Some(1) => vec![Part::One],
+        Some(2) => vec![Part::Two],
+        _ => Part::BOTH.to_vec(),
+    };
+
+    let source = args.selection.source();
+
+    let mut results = vec![];
+    for day in args.selection.days()? {
+        if args.stream {
+            results.extend(
+                runner::run_streaming(day, source.open(day.number)?)
+                    .with_context(|| format!("Day {} failed", day.number))?,
+            );
+            continue;
+        }
+
+        let input = source.read(day.number)?;
+
+        for &part in &parts {
+            results.push(
+                runner::run_part(day, part, &input)
+                    .with_context(|| format!("Day {} part {} failed", day.number, part))?,
+            );
+        }
+    }
+
+    match args.format {
+        Format::Table => print!("{}", runner::summary_table(&results)),
+        Format::Json => print!("{}", runner::results_json_lines(&results)),
+        Format::Csv => print!("{}", runner::results_csv(&results)),
+    }
+
+    Ok(())
+}
+
+fn run_animation(args: &RunArgs) -> anyhow::Result<()> {
+    if !(args.fps > 0.0 && args.fps.is_finite()) {
+        bail!("--fps must be a positive number, got {}", args.fps);
+    }
+
+    let part = match args.part {
+        Some(2) => Part::Two,
+        _ => Part::One,
+    };
+
+    let [day] = args.selection.days()?[..] else {
+        bail!("--animate needs a single --day");
+    };
+    let input = args.selection.source().read(day.number)?;
+
+    let Some(frames) = day.solution.animate(&input, part)? else {
+        bail!("Day {} has no animation", day.number);
+    };
+
+    // Stdin is line-buffered, so controls arrive a line at a time. The reader
+    // thread is left blocked on stdin when playback ends, which is harmless
+    // since the process exits right after
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(control) = Control::from_line(&line) {
+                if tx.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    animate::play(
+        frames,
+        args.fps,
+        args.paused,
+        &rx,
+        &mut std::io::stdout().lock(),
+    )?;
+
+    Ok(())
+}
+
+fn run_bench(args: &BenchArgs) -> anyhow::Result<()> {
+    let source = args.selection.source();
+
+    let mut results = vec![];
+    for day in args.selection.days()? {
+        let input = source.read(day.number)?;
+
+        results.push(
+            bench::bench_day(day, &input, args.iterations as usize)
+                .with_context(|| format!("Day {} failed", day.number))?,
+        );
+    }
+
+    print!("{}", bench::results_table(&results));
+
+    std::fs::write(&args.json, bench::results_json(&results))
+        .with_context(|| format!("Failed to write {}", args.json.display()))?;
+    println!("\nWrote {}", args.json.display());
+
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> anyhow::Result<()> {
+    let mut answers = Answers::load(&args.answers)?;
+    let source = args.selection.source();
+
+    let mut rows = vec![];
+    let mut mismatches = 0;
+    let mut recorded = 0;
+
+    for day in args.selection.days_or_all()? {
+        // A day without an input hasn't been downloaded yet, which is no reason
+        // to stop verifying the others
+        let input = match source.read(day.number) {
+            Ok(input) => input,
+            Err(e) if e.is::<MissingInput>() => {
+                rows.push((
+                    day.number,
+                    "-".to_string(),
+                    "-".to_string(),
+                    "no input".to_string(),
+                ));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        for part in Part::BOTH {
+            let result = runner::run_part(day, part, &input)
+                .with_context(|| format!("Day {} part {} failed", day.number, part))?;
+
+            let status = match answers.check(day.number, part, &result.answer) {
+                verdict @ Verdict::Mismatch { .. } => {
+                    mismatches += 1;
+                    verdict.to_string()
+                }
+                Verdict::Unrecorded if args.record && result.answer != Unsolved::ANSWER => {
+                    answers.insert(day.number, part, result.answer.clone());
+                    recorded += 1;
+                    "recorded".to_string()
+                }
+                verdict => verdict.to_string(),
+            };
+
+            rows.push((day.number, part.to_string(), result.answer, status));
+        }
+    }
+
+    println!("{:>3}  {:>4}  {:<20}  Status", "Day", "Part", "Answer");
+    for (day, part, answer, status) in &rows {
+        println!("{:>3}  {:>4}  {:<20}  {}", day, part, answer, status);
+    }
+
+    if recorded > 0 {
+        answers.save(&args.answers)?;
+        println!(
+            "\nRecorded {} new answer(s) in {}",
+            recorded,
+            args.answers.display()
+        );
+    }
+
+    if mismatches > 0 {
+        bail!(
+            "{} answer(s) no longer match {}",
+            mismatches,
+            args.answers.display()
+        );
+    }
+
+    Ok(())
+}
+
+fn render(args: &RenderArgs) -> anyhow::Result<()> {
+    let source = args.selection.source();
+    let palette = args.palette.clone().unwrap_or_default();
+    let extension = match args.image_format {
+        ImageFormat::Png => "png",
+        ImageFormat::Ppm => "ppm",
+    };
+
+    for day in args.selection.days()? {
+        let input = source.read(day.number)?;
+
+        let Some(cells) = day.solution.render(&input)? else {
+            println!("Skipped day {}: nothing to render", day.number);
+            continue;
+        };
+
+        let path = args
+            .out_dir
+            .join(format!("day{}.{}", day.number, extension));
+        Image::from_indices(&cells, args.cell_size as usize, &palette).save(&path)?;
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn explain(args: &ExplainArgs) -> anyhow::Result<()> {
+    let source = args.selection.source();
+
+    for day in args.selection.days()? {
+        let input = source.read(day.number)?;
+
+        let Some(explanation) = day.solution.explain(&input)? else {
+            if args.selection.all {
+                continue;
+            }
+            bail!("Day {} has no explanation", day.number);
+        };
+
+        match args.format {
+            Format::Table if args.selection.all => {
+                print!("Day {}\n{}\n", day.number, explanation.to_table())
+            }
+            Format::Table => print!("{}", explanation.to_table()),
+            Format::Json => print!("{}", explanation.to_json_lines()),
+            Format::Csv => print!("{}", explanation.to_csv()),
+        }
+    }
+
+    Ok(())
+}
+
+fn new_day(day: u8) -> anyhow::Result<()> {
+    for path in scaffold::new_day(day)? {
+        println!("Wrote {}", path);
+    }
+
+    Ok(())
+}
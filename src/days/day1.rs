@@ -0,0 +1,321 @@
+use std::{collections::HashMap, fmt::Display, io::BufRead};
+
+use anyhow::{ensure, Context};
+
+use crate::{explain::Explanation, grid::Grid, solution::Solution, sort::ExternalSorter};
+
+/// How many IDs per list the streaming mode sorts in memory before spilling
+/// them to disk, which is 8 MiB of each list.
+const STREAM_CHUNK_SIZE: usize = 1 << 20;
+
+/// Total distance between two lists once both are sorted, pairing the
+/// smallest with the smallest and so on.
+fn distance(left: &[usize], right: &[usize]) -> usize {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    left.sort_unstable();
+    right.sort_unstable();
+
+    sorted_distance(&left, &right)
+}
+
+fn sorted_distance(left: &[usize], right: &[usize]) -> usize {
+    left.iter().zip(right).map(|(l, r)| l.abs_diff(*r)).sum()
+}
+
+/// Sum of each number in `left` times how often it appears in `right`.
+fn similarity(left: &[usize], right: &[usize]) -> usize {
+    let mut right_counts: HashMap<usize, usize> = HashMap::new();
+    for &rn in right {
+        *right_counts.entry(rn).or_insert(0) += 1;
+    }
+
+    left.iter()
+        .map(|ln| ln * right_counts.get(ln).unwrap_or(&0))
+        .sum()
+}
+
+fn part_one(lists: &LocationLists) -> usize {
+    distance(&lists.columns[0], &lists.columns[1])
+}
+
+fn part_two(lists: &LocationLists) -> usize {
+    similarity(&lists.columns[0], &lists.columns[1])
+}
+
+/// Any number of location ID lists, one per whitespace-separated column.
+#[derive(Debug)]
+pub struct LocationLists {
+    columns: Vec<Vec<usize>>,
+}
+
+impl LocationLists {
+    /// Compares every ordered pair of columns.
+    pub fn compare_all(&self) -> Comparison {
+        let sorted = self
+            .columns
+            .iter()
+            .map(|column| {
+                let mut column = column.clone();
+                column.sort_unstable();
+                column
+            })
+            .collect::<Vec<_>>();
+
+        let n = self.columns.len();
+
+        Comparison {
+            distance: Grid::from_fn(n, n, |(i, j)| sorted_distance(&sorted[i], &sorted[j])),
+            similarity: Grid::from_fn(n, n, |(i, j)| {
+                similarity(&self.columns[i], &self.columns[j])
+            }),
+        }
+    }
+}
+
+/// Distance and similarity between each pair of columns, indexed by
+/// `(column, other column)`. Distance is symmetric, but similarity counts the
+/// first column's numbers in the second, so it generally isn't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub distance: Grid<usize>,
+    pub similarity: Grid<usize>,
+}
+
+impl Comparison {
+    /// Both matrices for `aoc explain`, one row per list and metric with a
+    /// column for each list it is compared with.
+    pub fn to_explanation(&self) -> Explanation {
+        let n = self.distance.ncols();
+        let mut columns = vec!["matrix".to_string(), "list".to_string()];
+        columns.extend((1..=n).map(|col| col.to_string()));
+
+        let mut explanation = Explanation::new(columns);
+        for (title, matrix) in [
+            ("distance", &self.distance),
+            ("similarity", &self.similarity),
+        ] {
+            for (row, values) in matrix.rows().enumerate() {
+                let mut fields = vec![title.into(), (row + 1).into()];
+                fields.extend(values.iter().map(|&value| value.into()));
+
+                explanation.push(fields);
+            }
+        }
+
+        explanation
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (title, matrix) in [
+            ("Distance", &self.distance),
+            ("Similarity", &self.similarity),
+        ] {
+            let width = matrix
+                .iter()
+                .map(|(_, value)| value.to_string().len())
+                .max()
+                .unwrap_or_default();
+
+            writeln!(f, "{}", title)?;
+            write!(f, "{:>3}", "")?;
+            for col in 1..=matrix.ncols() {
+                write!(f, "  {:>width$}", col)?;
+            }
+            writeln!(f)?;
+
+            for (row, values) in matrix.rows().enumerate() {
+                write!(f, "{:>3}", row + 1)?;
+                for value in values {
+                    write!(f, "  {:>width$}", value)?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses one line's location IDs, which are separated by any whitespace.
+fn parse_line(line: &str, line_number: usize) -> anyhow::Result<Vec<usize>> {
+    line.split_whitespace()
+        .map(|n| {
+            n.parse::<usize>()
+                .with_context(|| format!("Invalid location ID {:?} on line {}", n, line_number))
+        })
+        .collect()
+}
+
+/// Solves both parts for the first two columns in a single pass over `input`.
+///
+/// Each list streams into an [`ExternalSorter`] for the distance, so only
+/// `chunk_size` IDs per list are held at once. The similarity comes from a
+/// map counting how often each ID appears in either list, which only grows
+/// with the number of distinct IDs.
+fn solve_streaming(input: &mut dyn BufRead, chunk_size: usize) -> anyhow::Result<(usize, usize)> {
+    let mut left = ExternalSorter::new(chunk_size);
+    let mut right = ExternalSorter::new(chunk_size);
+    let mut counts: HashMap<usize, (usize, usize)> = HashMap::new();
+    let mut ncols = None;
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let numbers = parse_line(&line, i + 1)?;
+
+        let expected = *ncols.get_or_insert(numbers.len());
+        ensure!(expected >= 2, "Expected at least two columns in {:?}", line);
+        ensure!(
+            numbers.len() == expected,
+            "Line {} has {} columns, expected {}",
+            i + 1,
+            numbers.len(),
+            expected
+        );
+
+        left.push(numbers[0] as u64)?;
+        right.push(numbers[1] as u64)?;
+        counts.entry(numbers[0]).or_default().0 += 1;
+        counts.entry(numbers[1]).or_default().1 += 1;
+    }
+
+    ensure!(ncols.is_some(), "Input has no location IDs");
+
+    let mut distance = 0;
+    for (l, r) in left.finish()?.zip(right.finish()?) {
+        distance += l?.abs_diff(r?) as usize;
+    }
+
+    let similarity = counts
+        .iter()
+        .map(|(&n, &(in_left, in_right))| n * in_left * in_right)
+        .sum();
+
+    Ok((distance, similarity))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = LocationLists;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        let mut columns: Vec<Vec<usize>> = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let numbers = parse_line(line, i + 1)?;
+
+            if i == 0 {
+                ensure!(
+                    numbers.len() >= 2,
+                    "Expected at least two columns in {:?}",
+                    line
+                );
+                columns = vec![vec![]; numbers.len()];
+            }
+            ensure!(
+                numbers.len() == columns.len(),
+                "Line {} has {} columns, expected {}",
+                i + 1,
+                numbers.len(),
+                columns.len()
+            );
+
+            for (column, n) in columns.iter_mut().zip(numbers) {
+                column.push(n);
+            }
+        }
+
+        ensure!(!columns.is_empty(), "Input has no location IDs");
+
+        Ok(LocationLists { columns })
+    }
+
+    fn part_one(&self, lists: &Self::Input) -> usize {
+        part_one(lists)
+    }
+
+    fn part_two(&self, lists: &Self::Input) -> usize {
+        part_two(lists)
+    }
+
+    fn explain(&self, lists: &Self::Input) -> Option<Explanation> {
+        Some(lists.compare_all().to_explanation())
+    }
+
+    fn solve_streaming(&self, input: &mut dyn BufRead) -> Option<anyhow::Result<(usize, usize)>> {
+        Some(solve_streaming(input, STREAM_CHUNK_SIZE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DynSolution;
+
+    #[test]
+    fn test_compare_three_columns() {
+        let lists = Day1.parse("3 4\t1\n4  3 9\n2 5 4\n").unwrap();
+
+        let comparison = lists.compare_all();
+
+        assert_eq!(comparison.distance[(0, 1)], part_one(&lists));
+        assert_eq!(
+            comparison.distance,
+            Grid::from_vec(3, 3, vec![0, 3, 7, 3, 0, 6, 7, 6, 0])
+        );
+        assert_eq!(comparison.similarity[(0, 2)], 4);
+        assert_eq!(comparison.similarity[(2, 0)], 4);
+        assert_eq!(comparison.similarity[(1, 1)], 12);
+        assert_eq!(
+            comparison.to_string(),
+            "Distance\n     1  2  3\n  1  0  3  7\n  2  3  0  6\n  3  7  6  0\n\
+             Similarity\n      1   2   3\n  1   9   7   4\n  2   7  12   4\n  3   4   4  14\n"
+        );
+    }
+
+    #[test]
+    fn test_explain_reports_matrices() {
+        let explanation = DynSolution::explain(&Day1, "3 4\t1\n4  3 9\n2 5 4\n")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            explanation.to_csv(),
+            "matrix,list,1,2,3\n\
+             distance,1,0,3,7\n\
+             distance,2,3,0,6\n\
+             distance,3,7,6,0\n\
+             similarity,1,9,7,4\n\
+             similarity,2,7,12,4\n\
+             similarity,3,4,4,14\n"
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_parsed() {
+        let input = (0..500)
+            .map(|i| format!("{}   {}\n", i * 7919 % 1000, i * 104729 % 300))
+            .collect::<String>();
+        let lists = Day1.parse(&input).unwrap();
+
+        // A chunk size this small spills plenty of sorted runs to disk
+        let streamed = solve_streaming(&mut input.as_bytes(), 64).unwrap();
+
+        assert_eq!(streamed, (part_one(&lists), part_two(&lists)));
+        assert!(solve_streaming(&mut "1 2\n3\n".as_bytes(), 64).is_err());
+        assert!(solve_streaming(&mut "".as_bytes(), 64).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Day1.parse("1 2\n3\n").is_err());
+        assert!(Day1.parse("1\n").is_err());
+        assert!(Day1.parse("1 x\n").is_err());
+        assert!(Day1.parse("").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+
+use crate::{
+    grid::Grid,
+    search::{count_paths, Search},
+    solution::Solution,
+    visited::VisitedSet,
+};
+
+#[derive(Debug)]
+pub struct TopographicMap {
+    grid: Grid<u32>,
+}
+
+impl FromStr for TopographicMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |ch| ch.to_digit(10).context("Grid item must be 0-9"))?;
+
+        Ok(TopographicMap { grid })
+    }
+}
+
+impl TopographicMap {
+    fn get_valid_neighbors(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        self.grid
+            .neighbors(pos)
+            .filter(|&neighbor| self.grid[pos] + 1 == self.grid[neighbor])
+            .collect()
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.grid
+            .iter()
+            .filter(|(_, &height)| height == 0)
+            .map(|(pos, _)| pos)
+    }
+}
+
+/// Counts the peaks reachable from each trailhead, reusing one visited set
+/// for every trailhead's search.
+fn part_one(map: &TopographicMap) -> usize {
+    let mut seen = VisitedSet::for_grid(&map.grid);
+
+    map.trailheads()
+        .map(|start| {
+            seen.clear();
+
+            Search::new(start)
+                .bfs_with(&mut seen, |&pos| map.get_valid_neighbors(pos))
+                .into_iter()
+                .filter(|&pos| map.grid[pos] == 9)
+                .count()
+        })
+        .sum()
+}
+
+fn part_two(map: &TopographicMap) -> usize {
+    map.trailheads()
+        .map(|start| {
+            count_paths(
+                start,
+                |&pos| map.get_valid_neighbors(pos),
+                |&pos| map.grid[pos] == 9,
+            )
+        })
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = TopographicMap;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, map: &Self::Input) -> usize {
+        part_one(map)
+    }
+
+    fn part_two(&self, map: &Self::Input) -> usize {
+        part_two(map)
+    }
+
+    /// Every position on some hiking trail, colored by height. Positions no
+    /// trail passes through are left as background.
+    fn render(&self, map: &Self::Input) -> Option<Grid<usize>> {
+        // One set shared by every search, so it ends up holding them all
+        let mut on_trail = VisitedSet::for_grid(&map.grid);
+        for start in map.trailheads() {
+            Search::new(start).bfs_with(&mut on_trail, |&pos| map.get_valid_neighbors(pos));
+        }
+
+        Some(Grid::from_fn(map.grid.nrows(), map.grid.ncols(), |pos| {
+            if on_trail.contains(pos) {
+                map.grid[pos] as usize + 1
+            } else {
+                0
+            }
+        }))
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::solution::Solution;
+
 fn split_number(number: usize) -> (usize, usize) {
     let digit_count = number.ilog10() + 1;
 
@@ -34,13 +36,18 @@ fn blink(old_stone_map: &HashMap<usize, usize>) -> HashMap<usize, usize> {
     new_stone_map
 }
 
-fn part_one(input: &str) -> usize {
+fn parse_stones(input: &str) -> anyhow::Result<HashMap<usize, usize>> {
+    let mut stones = HashMap::new();
+    for s in input.trim().split(' ') {
+        *stones.entry(s.parse::<usize>()?).or_default() += 1;
+    }
+
+    Ok(stones)
+}
+
+fn part_one(stones: &HashMap<usize, usize>) -> usize {
     let blink_count = 25;
-    let mut stones = input
-        .trim()
-        .split(' ')
-        .map(|s| (s.parse::<usize>().unwrap(), 1_usize))
-        .collect::<HashMap<_, _>>();
+    let mut stones = stones.clone();
 
     for _ in 0..blink_count {
         stones = blink(&stones);
@@ -49,13 +56,9 @@ fn part_one(input: &str) -> usize {
     stones.values().sum()
 }
 
-fn part_two(input: &str) -> usize {
+fn part_two(stones: &HashMap<usize, usize>) -> usize {
     let blink_count = 75;
-    let mut stones = input
-        .trim()
-        .split(' ')
-        .map(|s| (s.parse::<usize>().unwrap(), 1_usize))
-        .collect::<HashMap<_, _>>();
+    let mut stones = stones.clone();
 
     for _ in 0..blink_count {
         stones = blink(&stones);
@@ -64,30 +67,22 @@ fn part_two(input: &str) -> usize {
     stones.values().sum()
 }
 
-fn main() {
-    let input = include_str!("../../inputs/day11.txt");
+pub struct Day11;
 
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Day11 {
+    type Input = HashMap<usize, usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
 
-    #[test]
-    fn test_part_one_d11() {
-        let input = r"125 17";
-        let res = part_one(input);
-
-        assert_eq!(res, 55312);
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        parse_stones(input)
     }
 
-    #[test]
-    fn test_part_two_d11() {
-        let input = r"125 17";
-        let res = part_two(input);
+    fn part_one(&self, stones: &Self::Input) -> usize {
+        part_one(stones)
+    }
 
-        assert_eq!(res, 55312);
+    fn part_two(&self, stones: &Self::Input) -> usize {
+        part_two(stones)
     }
 }
@@ -0,0 +1,71 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{grid::Grid, regions::Regions, solution::Solution};
+
+#[derive(Debug)]
+pub struct Garden {
+    grid: Grid<u8>,
+}
+
+impl FromStr for Garden {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |ch| Ok(ch as u8))?;
+
+        Ok(Garden { grid })
+    }
+}
+
+impl Display for Garden {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid.display_with(|&plant| plant as char))
+    }
+}
+
+impl Garden {
+    fn regions(&self) -> Regions {
+        Regions::new(&self.grid, |a, b| a == b)
+    }
+}
+
+fn part_one(garden: &Garden) -> usize {
+    garden
+        .regions()
+        .iter()
+        .map(|region| region.area * region.perimeter)
+        .sum()
+}
+
+fn part_two(garden: &Garden) -> usize {
+    garden
+        .regions()
+        .iter()
+        .map(|region| region.area * region.sides)
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, garden: &Self::Input) -> usize {
+        part_one(garden)
+    }
+
+    fn part_two(&self, garden: &Self::Input) -> usize {
+        part_two(garden)
+    }
+
+    /// Each region in its own color.
+    fn render(&self, garden: &Self::Input) -> Option<Grid<usize>> {
+        Some(garden.regions().labels().map(|&label| label + 1))
+    }
+}
@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{ParseError, Scanner},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct ClawMachine {
+    x1: i64,
+    y1: i64,
+    x2: i64,
+    y2: i64,
+    px: i64,
+    py: i64,
+}
+
+impl FromStr for ClawMachine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x1, y1) = parse_line(s, 0, "Button A: X+", ", Y+")?;
+        let (x2, y2) = parse_line(s, 1, "Button B: X+", ", Y+")?;
+        let (px, py) = parse_line(s, 2, "Prize: X=", ", Y=")?;
+
+        Ok(ClawMachine {
+            x1,
+            y1,
+            x2,
+            y2,
+            px,
+            py,
+        })
+    }
+}
+
+fn parse_line(
+    s: &str,
+    line: usize,
+    x_prefix: &str,
+    y_prefix: &str,
+) -> Result<(i64, i64), ParseError> {
+    let parse_pair = || {
+        let text = s
+            .lines()
+            .nth(line)
+            .ok_or_else(|| ParseError::new(1, 1, "", format!("expected {:?}", x_prefix)))?;
+
+        let mut scanner = Scanner::new(text);
+        scanner.expect(x_prefix)?;
+        let x = scanner.number()?;
+        scanner.expect(y_prefix)?;
+        let y = scanner.number()?;
+        scanner.finish()?;
+
+        Ok((x, y))
+    };
+
+    parse_pair().map_err(|e: ParseError| e.offset_lines(line))
+}
+
+impl ClawMachine {
+    // https://en.wikipedia.org/wiki/Cramer%27s_rule
+    fn solve(&self) -> Option<i64> {
+        let det = self.x1 * self.y2 - self.x2 * self.y1;
+        if det == 0 {
+            return None;
+        }
+
+        let det_a = self.px * self.y2 - self.x2 * self.py;
+        let det_b = self.x1 * self.py - self.px * self.y1;
+
+        if det_a % det != 0 || det_b % det != 0 {
+            return None;
+        }
+
+        let a = det_a / det;
+        let b = det_b / det;
+
+        Some(3 * a + b)
+    }
+}
+
+fn parse_machines(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let mut machines = vec![];
+    let mut line = 0;
+
+    for block in input.split("\n\n") {
+        if !block.trim().is_empty() {
+            machines.push(
+                block
+                    .parse::<ClawMachine>()
+                    .map_err(|e| e.offset_lines(line))?,
+            );
+        }
+
+        line += block.lines().count() + 1;
+    }
+
+    Ok(machines)
+}
+
+fn part_one(machines: &[ClawMachine]) -> i64 {
+    machines.iter().filter_map(|m| m.solve()).sum()
+}
+
+fn part_two(machines: &[ClawMachine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|m| {
+            ClawMachine {
+                x1: m.x1,
+                y1: m.y1,
+                x2: m.x2,
+                y2: m.y2,
+                px: m.px + 10000000000000,
+                py: m.py + 10000000000000,
+            }
+            .solve()
+        })
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_machines(input)?)
+    }
+
+    fn part_one(&self, machines: &Self::Input) -> i64 {
+        part_one(machines)
+    }
+
+    fn part_two(&self, machines: &Self::Input) -> i64 {
+        part_two(machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nButton B: X+67 Y+21\nPrize: X=12748, Y=12176";
+
+        let err = parse_machines(input).unwrap_err();
+
+        assert_eq!(err, ParseError::new(6, 15, "Y+21", r#"expected ", Y+""#));
+    }
+}
@@ -0,0 +1,288 @@
+use std::{ops::Range, str::FromStr};
+
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{
+    animate::{Frame, Frames},
+    geometry::Point,
+    grid::Grid,
+    parse::{ParseError, Scanner},
+    solution::{Part, Solution},
+    sparse::SparseGrid,
+};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Robot {
+    p: Point,
+    v: Point,
+}
+
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(s);
+
+        scanner.expect("p=")?;
+        let px = scanner.number()?;
+        scanner.expect(",")?;
+        let py = scanner.number()?;
+
+        scanner.expect(" v=")?;
+        let vx = scanner.number()?;
+        scanner.expect(",")?;
+        let vy = scanner.number()?;
+
+        scanner.finish()?;
+
+        Ok(Robot {
+            p: Point::new(py, px),
+            v: Point::new(vy, vx),
+        })
+    }
+}
+
+impl Robot {
+    /// Moves one second's worth, teleporting across the room's edges.
+    fn step(&mut self, room: &Grid<()>) {
+        self.p = room.wrap(self.p + self.v).into();
+    }
+}
+
+/// The room's floor plan. Robots only need its shape to wrap around its
+/// edges, so the tiles hold nothing.
+fn empty_room(width: usize, height: usize) -> Grid<()> {
+    Grid::new(height, width, ())
+}
+
+/// A tile holding `count` robots, drawn as a digit capped at 9.
+fn count_char(count: usize) -> char {
+    match count {
+        0 => '.',
+        n => char::from_digit(n.min(9) as u32, 10).unwrap(),
+    }
+}
+
+fn range_contains(range: &Range<isize>, n: &isize) -> bool {
+    range.contains(n)
+}
+
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| l.parse().map_err(|e: ParseError| e.offset_lines(i)))
+        .collect()
+}
+
+fn part_one(robots: &[Robot], width: usize, height: usize) -> i64 {
+    let mut robots = robots.to_vec();
+    let room = empty_room(width, height);
+
+    robots.par_iter_mut().for_each(|r| {
+        for _ in 0..100 {
+            r.step(&room);
+        }
+    });
+
+    let (width, height) = (width as isize, height as isize);
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    let ul_range_x = 0..mid_x;
+    let ul_range_y = 0..mid_y;
+
+    let ur_range_x = mid_x + 1..width;
+    let ur_range_y = 0..mid_y;
+
+    let dl_range_x = 0..mid_x;
+    let dl_range_y = mid_y + 1..height;
+
+    let dr_range_x = mid_x + 1..width;
+    let dr_range_y = mid_y + 1..height;
+
+    let mut ur = 0;
+    let mut ul = 0;
+    let mut dr = 0;
+    let mut dl = 0;
+    for robot in &robots {
+        let (x, y) = (&robot.p.col, &robot.p.row);
+
+        if range_contains(&ul_range_x, x) && range_contains(&ul_range_y, y) {
+            ul += 1;
+        }
+
+        if range_contains(&ur_range_x, x) && range_contains(&ur_range_y, y) {
+            ur += 1;
+        }
+
+        if range_contains(&dl_range_x, x) && range_contains(&dl_range_y, y) {
+            dl += 1;
+        }
+
+        if range_contains(&dr_range_x, x) && range_contains(&dr_range_y, y) {
+            dr += 1;
+        }
+    }
+
+    ur * ul * dr * dl
+}
+
+fn std_deviation(data: &[isize]) -> f32 {
+    let sum = data.iter().sum::<isize>() as f32;
+    let count = data.len() as f32;
+    let mean = sum / count;
+    let variance = data
+        .iter()
+        .map(|value| {
+            let distance = mean - *value as f32;
+            distance * distance
+        })
+        .sum::<f32>()
+        / count;
+
+    variance.sqrt()
+}
+
+/// The robots bunch up to draw the Easter egg, so look for a moment when
+/// their positions spread much less than usual.
+fn is_easter_egg(robots: &[Robot]) -> bool {
+    let (xs, ys): (Vec<isize>, Vec<isize>) = robots
+        .iter()
+        .map(|robot| (robot.p.col, robot.p.row))
+        .unzip();
+
+    std_deviation(&xs) < 27.0 && std_deviation(&ys) < 27.0
+}
+
+/// Steps the robots until they form the Easter egg, returning how many
+/// seconds that took and where the robots ended up.
+fn find_easter_egg(robots: &[Robot], width: usize, height: usize) -> (usize, Vec<Robot>) {
+    let mut robots = robots.to_vec();
+    let room = empty_room(width, height);
+
+    let mut res = 0;
+
+    loop {
+        robots.iter_mut().for_each(|robot| robot.step(&room));
+
+        res += 1;
+
+        if is_easter_egg(&robots) {
+            return (res, robots);
+        }
+    }
+}
+
+fn part_two(robots: &[Robot], width: usize, height: usize) -> usize {
+    let (seconds, _) = find_easter_egg(robots, width, height);
+
+    seconds
+}
+
+/// The robots on each tile. Robots that start outside the room, say because
+/// the room size doesn't match the input, are kept where they are.
+fn robot_counts(robots: &[Robot]) -> SparseGrid<Robot> {
+    robots.iter().map(|robot| (robot.p, *robot)).collect()
+}
+
+/// Robots move around a room of `width` by `height` tiles, which is
+/// 101x103 for real inputs and 11x7 for the example.
+pub struct Day14 {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+    type PartOne = i64;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_robots(input)?)
+    }
+
+    fn part_one(&self, robots: &Self::Input) -> i64 {
+        part_one(robots, self.width, self.height)
+    }
+
+    fn part_two(&self, robots: &Self::Input) -> usize {
+        part_two(robots, self.width, self.height)
+    }
+
+    /// The robots moving each second, until they form the Easter egg. Each
+    /// frame covers the tiles the robots occupy at that moment.
+    fn animate(&self, mut robots: Self::Input, _part: Part) -> Option<Frames> {
+        if robots.is_empty() {
+            return None;
+        }
+
+        let (width, height) = (self.width, self.height);
+        let frame = move |robots: &[Robot], seconds: usize| {
+            let counts = robot_counts(robots);
+            let (min, _) = counts.bounds().expect("There is at least one robot");
+
+            Frame::new(
+                counts
+                    .to_grid(|robots| count_char(robots.len()))
+                    .expect("There is at least one robot"),
+                robots.iter().filter_map(|robot| (robot.p - min).to_pos()),
+                format!("{} seconds", seconds),
+            )
+        };
+
+        let first = frame(&robots, 0);
+        let room = empty_room(width, height);
+        let mut seconds = 0;
+        let mut done = false;
+
+        let rest = std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            robots.iter_mut().for_each(|robot| robot.step(&room));
+            seconds += 1;
+            done = is_easter_egg(&robots);
+
+            Some(frame(&robots, seconds))
+        });
+
+        Some(Box::new(std::iter::once(first).chain(rest)))
+    }
+
+    /// The robots at the moment they form the Easter egg, colored by how many
+    /// share each tile.
+    fn render(&self, robots: &Self::Input) -> Option<Grid<usize>> {
+        let (_, robots) = find_easter_egg(robots, self.width, self.height);
+
+        robot_counts(&robots).to_grid(<[Robot]>::len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_robots("p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2x").unwrap_err();
+
+        assert_eq!(err, ParseError::new(3, 14, "x", "unexpected trailing text"));
+    }
+
+    #[test]
+    fn test_robots_outside_the_room() {
+        let day = Day14 {
+            width: 11,
+            height: 7,
+        };
+        let robots = parse_robots("p=0,0 v=1,1\np=20,2 v=1,1\np=20,2 v=0,0").unwrap();
+
+        let first = day.animate(robots, Part::Two).unwrap().next().unwrap();
+
+        assert_eq!((first.grid.nrows(), first.grid.ncols()), (3, 21));
+        assert_eq!(first.grid[(2, 20)], '2');
+        assert!(first.highlight.contains(&(2, 20)));
+    }
+}
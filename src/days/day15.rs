@@ -0,0 +1,263 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::Context;
+
+use crate::{
+    animate::{Frame, Frames},
+    geometry::Direction,
+    grid::Grid,
+    image,
+    parse::ParseError,
+    solution::{Part, Solution, Unsolved},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum GridItem {
+    Empty,
+    Wall,
+    Box,
+    Robot,
+}
+
+#[derive(Debug, Clone)]
+struct Warehouse {
+    grid: Grid<GridItem>,
+    robot_pos: (usize, usize),
+}
+
+#[derive(Debug)]
+pub struct Instructions {
+    warehouse: Warehouse,
+    moves: Vec<Direction>,
+}
+
+impl FromStr for Instructions {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (grid_str, moves_str) = s
+            .split_once("\n\n")
+            .context("Expected a blank line between the warehouse and the moves")?;
+
+        Ok(Instructions {
+            warehouse: grid_str.parse()?,
+            moves: parse_moves(moves_str)
+                .map_err(|e| e.offset_lines(grid_str.lines().count() + 1))?,
+        })
+    }
+}
+
+impl From<char> for GridItem {
+    fn from(ch: char) -> Self {
+        match ch {
+            '#' => Self::Wall,
+            'O' => Self::Box,
+            '@' => Self::Robot,
+            _ => Self::Empty,
+        }
+    }
+}
+
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.chars())
+    }
+}
+
+impl FromStr for Warehouse {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |ch| Ok(GridItem::from(ch)))?;
+        let robot_pos = grid
+            .position(|item| *item == GridItem::Robot)
+            .context("Warehouse has no robot")?;
+
+        Ok(Warehouse { grid, robot_pos })
+    }
+}
+
+fn parse_moves(moves_str: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut moves = vec![];
+
+    for (row, line) in moves_str.lines().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            if ch.is_whitespace() {
+                continue;
+            }
+
+            let dir = Direction::try_from(ch).map_err(|ch| {
+                ParseError::new(
+                    row + 1,
+                    col + 1,
+                    ch,
+                    r#"expected one of "<", ">", "^", "v""#,
+                )
+            })?;
+            moves.push(dir);
+        }
+    }
+
+    Ok(moves)
+}
+
+impl Warehouse {
+    fn chars(&self) -> Grid<char> {
+        self.grid.map(|item| match item {
+            GridItem::Empty => '.',
+            GridItem::Wall => '#',
+            GridItem::Box => 'O',
+            GridItem::Robot => '@',
+        })
+    }
+
+    fn do_move(&mut self, dir: Direction) {
+        let next_robot_pos = self.grid.step(self.robot_pos, dir);
+
+        if let Some((row, col)) = next_robot_pos {
+            match self.grid[(row, col)] {
+                GridItem::Empty => {
+                    self.grid[self.robot_pos] = GridItem::Empty;
+                    self.grid[(row, col)] = GridItem::Robot;
+                    self.robot_pos = (row, col);
+                }
+                GridItem::Wall => {}
+                GridItem::Box => {
+                    let mut scan = (row, col);
+                    while let Some(next_box_pos) = self.grid.step(scan, dir) {
+                        match self.grid[next_box_pos] {
+                            GridItem::Empty => {
+                                self.grid[self.robot_pos] = GridItem::Empty;
+                                self.grid[next_box_pos] = GridItem::Box;
+                                self.grid[(row, col)] = GridItem::Robot;
+                                self.robot_pos = (row, col);
+                                break;
+                            }
+                            GridItem::Wall => break,
+                            GridItem::Box => {
+                                scan = next_box_pos;
+                            }
+
+                            GridItem::Robot => unreachable!("Robot should only have one position"),
+                        }
+                    }
+                }
+                GridItem::Robot => unreachable!("Robot should only have one position"),
+            }
+        }
+    }
+}
+
+impl Instructions {
+    /// The warehouse once the robot has made every move.
+    fn final_warehouse(&self) -> Warehouse {
+        let mut warehouse = self.warehouse.clone();
+
+        self.moves.iter().for_each(|dir| warehouse.do_move(*dir));
+
+        warehouse
+    }
+}
+
+fn part_one(instructions: &Instructions) -> usize {
+    let warehouse = instructions.final_warehouse();
+
+    let mut res = 0;
+    for ((row, col), item) in warehouse.grid.iter() {
+        if *item == GridItem::Box {
+            res += 100 * row + col;
+        }
+    }
+
+    res
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Instructions;
+    type PartOne = usize;
+    type PartTwo = Unsolved;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> usize {
+        part_one(instructions)
+    }
+
+    fn part_two(&self, _instructions: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    /// The warehouse after every move, with walls, boxes and the robot each in
+    /// their own color.
+    fn render(&self, instructions: &Self::Input) -> Option<Grid<usize>> {
+        Some(image::char_indices(&instructions.final_warehouse().chars()))
+    }
+
+    /// The robot working through its moves. Part two isn't solved yet, so
+    /// there's nothing to show for it.
+    fn animate(&self, instructions: Self::Input, part: Part) -> Option<Frames> {
+        if part == Part::Two {
+            return None;
+        }
+
+        let Instructions {
+            mut warehouse,
+            moves,
+        } = instructions;
+
+        let total = moves.len();
+        let first = Frame::new(
+            warehouse.chars(),
+            [warehouse.robot_pos],
+            format!("move 0/{}", total),
+        );
+
+        let rest = moves.into_iter().enumerate().map(move |(i, dir)| {
+            warehouse.do_move(dir);
+
+            Frame::new(
+                warehouse.chars(),
+                [warehouse.robot_pos],
+                format!("move {}/{}: {}", i + 1, total, dir.to_char()),
+            )
+        });
+
+        Some(Box::new(std::iter::once(first).chain(rest)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_final_warehouse() {
+        let instructions = Day15.parse("#####\n#@O.#\n#####\n\n>>").unwrap();
+
+        let cells = Day15.render(&instructions).unwrap();
+
+        // The box stops against the wall, with the robot right behind it
+        assert_eq!(cells.row(1), &[1, 0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_parse_moves_error() {
+        let input = "####\n#@.#\n####\n\n<^^>\n>v<x";
+
+        let err = input.parse::<Instructions>().unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<ParseError>(),
+            Some(&ParseError::new(
+                6,
+                4,
+                "x",
+                r#"expected one of "<", ">", "^", "v""#
+            ))
+        );
+    }
+}
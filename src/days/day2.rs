@@ -0,0 +1,404 @@
+use std::fmt::Display;
+
+use crate::{explain::Explanation, solution::Solution};
+
+/// Which way the levels of a safe report may move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directions {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as the whole report agrees.
+    Either,
+}
+
+/// Why a report isn't safe, judged at the first step that breaks the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Problem {
+    /// The first step moves the way the policy forbids.
+    WrongDirection,
+    /// A step moves against the direction the report started in.
+    DirectionChange,
+    /// A step stays level or moves by less than the minimum.
+    StepTooSmall,
+    StepTooLarge,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Problem::WrongDirection => "wrong direction",
+            Problem::DirectionChange => "direction change",
+            Problem::StepTooSmall => "step too small",
+            Problem::StepTooLarge => "step too large",
+        })
+    }
+}
+
+/// How a single report fares under a policy's step rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// The first problem and the index of the level the bad step lands on,
+    /// or `None` if the report is safe as is.
+    pub problem: Option<(Problem, usize)>,
+    /// The lowest index of a level whose removal makes an unsafe report safe,
+    /// if there is one.
+    pub removal: Option<usize>,
+}
+
+impl Diagnosis {
+    pub fn verdict(&self) -> &'static str {
+        match (self.problem, self.removal) {
+            (None, _) => "safe",
+            (Some(_), Some(_)) => "safe with dampener",
+            (Some(_), None) => "unsafe",
+        }
+    }
+}
+
+/// What makes a report safe: every step between adjacent levels moves the
+/// same allowed way by `min_step..=max_step`, after removing at most
+/// `tolerance` bad levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: usize,
+    pub max_step: usize,
+    pub directions: Directions,
+    pub tolerance: usize,
+}
+
+impl SafetyPolicy {
+    /// The reactor's rules for part one.
+    pub const STRICT: SafetyPolicy = SafetyPolicy {
+        min_step: 1,
+        max_step: 3,
+        directions: Directions::Either,
+        tolerance: 0,
+    };
+
+    /// Part two's rules, where the Problem Dampener tolerates one bad level.
+    pub const DAMPENED: SafetyPolicy = SafetyPolicy {
+        tolerance: 1,
+        ..SafetyPolicy::STRICT
+    };
+
+    fn is_step_ok(&self, from: usize, to: usize, increasing: bool) -> bool {
+        let right_way = if increasing { to > from } else { to < from };
+
+        right_way && (self.min_step..=self.max_step).contains(&from.abs_diff(to))
+    }
+
+    /// The fewest levels to remove so that the rest move `increasing` (or
+    /// not), if that is within the tolerance.
+    ///
+    /// `removals[j]` is the fewest removals among levels `0..=j` with level
+    /// `j` kept. Keeping `j` after `i` removes everything between them, so
+    /// only the `tolerance + 1` levels before `j` can come before it, which
+    /// keeps this O(n * (tolerance + 1)): linear for the dampener.
+    fn min_removals(&self, report: &[usize], increasing: bool) -> Option<usize> {
+        let n = report.len();
+
+        let mut removals: Vec<usize> = Vec::with_capacity(n);
+        for j in 0..n {
+            // Keeping `j` as the first level removes everything before it
+            let best = (j.saturating_sub(self.tolerance + 1)..j)
+                .filter(|&i| self.is_step_ok(report[i], report[j], increasing))
+                .map(|i| removals[i] + (j - i - 1))
+                .fold(j, usize::min);
+
+            removals.push(best);
+        }
+
+        let total = (0..n).map(|j| removals[j] + (n - 1 - j)).min().unwrap_or(0);
+
+        (total <= self.tolerance).then_some(total)
+    }
+
+    /// The first step of `report` that breaks the rules with nothing removed.
+    /// The direction comes from the policy, or from the first step if either
+    /// way is allowed.
+    fn first_problem(&self, report: &[usize]) -> Option<(Problem, usize)> {
+        let (&first, &second) = (report.first()?, report.get(1)?);
+        let increasing = match self.directions {
+            Directions::Increasing => true,
+            Directions::Decreasing => false,
+            Directions::Either => second > first,
+        };
+
+        (1..report.len()).find_map(|i| {
+            let (from, to) = (report[i - 1], report[i]);
+            let diff = from.abs_diff(to);
+
+            let problem = if from != to && (to > from) != increasing {
+                if i == 1 {
+                    Problem::WrongDirection
+                } else {
+                    Problem::DirectionChange
+                }
+            } else if diff == 0 || diff < self.min_step {
+                Problem::StepTooSmall
+            } else if diff > self.max_step {
+                Problem::StepTooLarge
+            } else {
+                return None;
+            };
+
+            Some((problem, i))
+        })
+    }
+
+    /// Why `report` is unsafe and which single level the dampener would
+    /// remove to fix it. This looks at one removal whatever the tolerance.
+    ///
+    /// With the first bad step landing on level `i`, removing any level after
+    /// it leaves that step in place, and removing one between the second and
+    /// `i - 1` can only join two good steps into a longer one. So only the
+    /// first two levels, which set the direction, and the two levels of the
+    /// bad step are worth trying.
+    pub fn diagnose(&self, report: &[usize]) -> Diagnosis {
+        let problem = self.first_problem(report);
+
+        let removal = problem.and_then(|(_, i)| {
+            let mut candidates = vec![0, 1, i - 1, i];
+            candidates.sort_unstable();
+            candidates.dedup();
+
+            candidates.into_iter().find(|&j| {
+                let mut rest = report.to_vec();
+                rest.remove(j);
+                self.first_problem(&rest).is_none()
+            })
+        });
+
+        Diagnosis { problem, removal }
+    }
+
+    pub fn is_safe(&self, report: &[usize]) -> bool {
+        let increasing = matches!(self.directions, Directions::Increasing | Directions::Either)
+            && self.min_removals(report, true).is_some();
+        let decreasing = matches!(self.directions, Directions::Decreasing | Directions::Either)
+            && self.min_removals(report, false).is_some();
+
+        increasing || decreasing
+    }
+}
+
+fn part_one(data: &[Vec<usize>]) -> usize {
+    data.iter()
+        .filter(|report| SafetyPolicy::STRICT.is_safe(report))
+        .count()
+}
+
+fn part_two(data: &[Vec<usize>]) -> usize {
+    data.iter()
+        .filter(|report| SafetyPolicy::DAMPENED.is_safe(report))
+        .count()
+}
+
+fn explain(data: &[Vec<usize>]) -> Explanation {
+    let mut explanation = Explanation::new(vec![
+        "report", "levels", "verdict", "problem", "index", "removal",
+    ]);
+
+    for (i, report) in data.iter().enumerate() {
+        let diagnosis = SafetyPolicy::DAMPENED.diagnose(report);
+        let levels = report
+            .iter()
+            .map(|level| level.to_string())
+            .collect::<Vec<_>>();
+
+        explanation.push(vec![
+            (i + 1).into(),
+            levels.join(" ").into(),
+            diagnosis.verdict().into(),
+            diagnosis
+                .problem
+                .map(|(problem, _)| problem.to_string())
+                .into(),
+            diagnosis.problem.map(|(_, index)| index).into(),
+            diagnosis.removal.into(),
+        ]);
+    }
+
+    explanation
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<usize>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        let data = input
+            .lines()
+            .map(|line| {
+                line.split(' ')
+                    .map(|ch| ch.parse::<usize>())
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(data)
+    }
+
+    fn part_one(&self, data: &Self::Input) -> usize {
+        part_one(data)
+    }
+
+    fn part_two(&self, data: &Self::Input) -> usize {
+        part_two(data)
+    }
+
+    fn explain(&self, data: &Self::Input) -> Option<Explanation> {
+        Some(explain(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tries every way of removing up to `tolerance` levels.
+    fn brute_force(policy: &SafetyPolicy, report: &[usize]) -> bool {
+        let moves = |kept: &[usize], increasing: bool| {
+            kept.windows(2)
+                .all(|pair| policy.is_step_ok(pair[0], pair[1], increasing))
+        };
+
+        (0_u32..1 << report.len())
+            .filter(|removed| removed.count_ones() as usize <= policy.tolerance)
+            .any(|removed| {
+                let kept = report
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| removed & (1 << i) == 0)
+                    .map(|(_, &level)| level)
+                    .collect::<Vec<_>>();
+
+                match policy.directions {
+                    Directions::Increasing => moves(&kept, true),
+                    Directions::Decreasing => moves(&kept, false),
+                    Directions::Either => moves(&kept, true) || moves(&kept, false),
+                }
+            })
+    }
+
+    const DIRECTIONS: [Directions; 3] = [
+        Directions::Increasing,
+        Directions::Decreasing,
+        Directions::Either,
+    ];
+
+    /// `n` pseudo-random reports of 1 to 9 levels between 0 and 11.
+    fn random_reports(seed: u32, n: usize) -> Vec<Vec<usize>> {
+        let mut state = seed;
+        let mut next = |n: u32| {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            (state >> 16) % n
+        };
+
+        (0..n)
+            .map(|_| {
+                let len = 1 + next(9);
+                (0..len).map(|_| next(12) as usize).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        for (i, report) in random_reports(12345, 2000).into_iter().enumerate() {
+            // Cycles through every combination of policy settings
+            let policy = SafetyPolicy {
+                min_step: i % 2,
+                max_step: 2 + i / 2 % 3,
+                directions: DIRECTIONS[i / 6 % 3],
+                tolerance: i / 18 % 4,
+            };
+
+            assert_eq!(
+                policy.is_safe(&report),
+                brute_force(&policy, &report),
+                "{:?} under {:?}",
+                report,
+                policy
+            );
+        }
+    }
+
+    #[test]
+    fn test_diagnose_matches_exhaustive_removal() {
+        for (i, report) in random_reports(54321, 2000).into_iter().enumerate() {
+            let policy = SafetyPolicy {
+                directions: DIRECTIONS[i % 3],
+                ..SafetyPolicy::DAMPENED
+            };
+            let strict = SafetyPolicy {
+                tolerance: 0,
+                ..policy
+            };
+
+            let diagnosis = policy.diagnose(&report);
+            let exhaustive = (0..report.len()).find(|&j| {
+                let mut rest = report.clone();
+                rest.remove(j);
+                strict.is_safe(&rest)
+            });
+
+            assert_eq!(diagnosis.problem.is_none(), strict.is_safe(&report));
+            assert_eq!(
+                diagnosis.verdict() != "unsafe",
+                policy.is_safe(&report),
+                "{:?} under {:?}",
+                report,
+                policy
+            );
+            if diagnosis.problem.is_some() {
+                assert_eq!(diagnosis.removal, exhaustive, "{:?}", report);
+            }
+        }
+    }
+
+    #[test]
+    fn test_explain_example() {
+        let data = Day2
+            .parse(include_str!("../../examples/day2/example.txt"))
+            .unwrap();
+
+        assert_eq!(
+            explain(&data).to_table(),
+            "report  levels     verdict             problem           index  removal\n\
+             -----------------------------------------------------------------------\n\
+             1       7 6 4 2 1  safe                -                 -      -\n\
+             2       1 2 7 8 9  unsafe              step too large    2      -\n\
+             3       9 7 6 2 1  unsafe              step too large    3      -\n\
+             4       1 3 2 4 5  safe with dampener  direction change  2      1\n\
+             5       8 6 4 4 1  safe with dampener  step too small    3      2\n\
+             6       1 3 6 7 9  safe                -                 -      -\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_policies() {
+        let increasing = SafetyPolicy {
+            directions: Directions::Increasing,
+            ..SafetyPolicy::DAMPENED
+        };
+        assert!(increasing.is_safe(&[1, 3, 2, 4, 5]));
+        assert!(!increasing.is_safe(&[7, 6, 4, 2, 1]));
+
+        let wide = SafetyPolicy {
+            max_step: 5,
+            ..SafetyPolicy::STRICT
+        };
+        assert!(wide.is_safe(&[1, 2, 7, 8, 9]));
+
+        let lenient = SafetyPolicy {
+            tolerance: 2,
+            ..SafetyPolicy::STRICT
+        };
+        assert!(lenient.is_safe(&[1, 9, 2, 9, 3]));
+        assert!(!SafetyPolicy::DAMPENED.is_safe(&[1, 9, 2, 9, 3]));
+    }
+}
@@ -0,0 +1,425 @@
+use std::fmt::Display;
+
+use crate::{
+    explain::{Explanation, Field},
+    solution::Solution,
+};
+
+/// An instruction found in corrupted memory, like `mul(2,4)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// Byte offset of the instruction's name in the program.
+    pub offset: usize,
+    pub name: &'a str,
+    pub args: Vec<i64>,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+
+        write!(f, "{}({})", self.name, args.join(","))
+    }
+}
+
+/// What an accumulating instruction computes from its arguments, or `None`
+/// if the result overflows.
+type Operation = Box<dyn Fn(&[i64]) -> Option<i64>>;
+
+enum Effect {
+    Enable,
+    Disable,
+    /// Adds the result to the total while enabled.
+    Accumulate(Operation),
+}
+
+struct Instruction {
+    name: String,
+    arity: usize,
+    effect: Effect,
+}
+
+/// One executed instruction in an interpreter's trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub token: Token<'a>,
+    /// Whether instructions were enabled once this one ran.
+    pub enabled: bool,
+    /// What this instruction added to the total, if it was enabled and
+    /// computes anything.
+    pub value: Option<i64>,
+    /// Whether the instruction was skipped because its result, or the total
+    /// with it added, overflowed.
+    pub overflowed: bool,
+}
+
+/// The result of running a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run<'a> {
+    pub total: i64,
+    pub trace: Vec<Step<'a>>,
+}
+
+/// Finds and runs the instructions hidden in corrupted memory. An instruction
+/// is a registered name, then its arguments as comma-separated decimal numbers
+/// of any length in parentheses, with nothing else in between. Everything
+/// else is garbage and skipped, including arguments too large for an `i64`.
+/// Instructions whose results overflow are skipped and flagged in the trace.
+///
+/// ```
+/// use aoc_2024::days::day3::Interpreter;
+///
+/// let interpreter = Interpreter::new()
+///     .instruction("mul", 2, |args| args[0].checked_mul(args[1]))
+///     .instruction("sub", 2, |args| args[0].checked_sub(args[1]))
+///     .conditionals("do", "don't");
+///
+/// let run = interpreter.run("mul(12,100)]sub(1,1000)don't()_mul(5,5)");
+///
+/// assert_eq!(run.total, 201);
+/// assert_eq!(run.trace.len(), 4);
+/// ```
+#[derive(Default)]
+pub struct Interpreter {
+    instructions: Vec<Instruction>,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The elves' language: `mul`, switched on and off by `do` and `don't`.
+    pub fn standard() -> Self {
+        Interpreter::new()
+            .instruction("mul", 2, mul)
+            .conditionals("do", "don't")
+    }
+
+    /// Registers an instruction taking exactly `arity` arguments, whose result
+    /// is added to the total while instructions are enabled. `f` returns
+    /// `None` when the result overflows.
+    pub fn instruction<F>(self, name: &str, arity: usize, f: F) -> Self
+    where
+        F: Fn(&[i64]) -> Option<i64> + 'static,
+    {
+        self.register(name, arity, Effect::Accumulate(Box::new(f)))
+    }
+
+    /// Registers argument-less instructions that enable and disable every
+    /// other instruction. Instructions start enabled.
+    pub fn conditionals(self, enable: &str, disable: &str) -> Self {
+        self.register(enable, 0, Effect::Enable)
+            .register(disable, 0, Effect::Disable)
+    }
+
+    fn register(mut self, name: &str, arity: usize, effect: Effect) -> Self {
+        assert!(!name.is_empty(), "Instruction names can't be empty");
+
+        self.instructions
+            .retain(|instruction| instruction.name != name);
+        self.instructions.push(Instruction {
+            name: name.to_string(),
+            arity,
+            effect,
+        });
+        // Longest first, so `don't` wins over a `don` registered alongside it
+        self.instructions
+            .sort_by_key(|instruction| std::cmp::Reverse(instruction.name.len()));
+
+        self
+    }
+
+    /// Every well-formed instruction in `program`, in order.
+    pub fn tokenize<'a>(&self, program: &'a str) -> Vec<Token<'a>> {
+        self.lex(program).map(|(token, _)| token).collect()
+    }
+
+    fn lex<'a, 'b>(&'b self, program: &'a str) -> impl Iterator<Item = (Token<'a>, &'b Effect)> {
+        let bytes = program.as_bytes();
+        let mut offset = 0;
+
+        std::iter::from_fn(move || {
+            while offset < bytes.len() {
+                let start = offset;
+                offset += 1;
+
+                for instruction in &self.instructions {
+                    let rest = &bytes[start..];
+                    if !rest.starts_with(instruction.name.as_bytes()) {
+                        continue;
+                    }
+
+                    let after_name = start + instruction.name.len();
+                    if let Some((args, end)) = parse_args(bytes, after_name, instruction.arity) {
+                        offset = end;
+
+                        let token = Token {
+                            offset: start,
+                            name: &program[start..after_name],
+                            args,
+                        };
+                        return Some((token, &instruction.effect));
+                    }
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Runs every instruction in `program` in order, recording each one.
+    pub fn run<'a>(&self, program: &'a str) -> Run<'a> {
+        let mut enabled = true;
+        let mut total: i64 = 0;
+        let mut trace = vec![];
+
+        for (token, effect) in self.lex(program) {
+            let mut overflowed = false;
+            let value = match effect {
+                Effect::Enable => {
+                    enabled = true;
+                    None
+                }
+                Effect::Disable => {
+                    enabled = false;
+                    None
+                }
+                Effect::Accumulate(_) if !enabled => None,
+                Effect::Accumulate(f) => {
+                    let value = f(&token.args).filter(|&value| total.checked_add(value).is_some());
+                    overflowed = value.is_none();
+                    total += value.unwrap_or(0);
+                    value
+                }
+            };
+
+            trace.push(Step {
+                token,
+                enabled,
+                value,
+                overflowed,
+            });
+        }
+
+        Run { total, trace }
+    }
+}
+
+/// Parses `(a,b,...)` with exactly `arity` numbers starting at `start`,
+/// returning the numbers and the offset just past the closing parenthesis.
+fn parse_args(bytes: &[u8], start: usize, arity: usize) -> Option<(Vec<i64>, usize)> {
+    let mut pos = start;
+    let expect = |byte: u8, pos: &mut usize| {
+        let found = bytes.get(*pos) == Some(&byte);
+        *pos += found as usize;
+        found
+    };
+
+    if !expect(b'(', &mut pos) {
+        return None;
+    }
+
+    let mut args = Vec::with_capacity(arity);
+    for i in 0..arity {
+        if i > 0 && !expect(b',', &mut pos) {
+            return None;
+        }
+
+        let digits = bytes[pos..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        // Digits are ASCII, so this is always valid UTF-8, but may overflow
+        let number = std::str::from_utf8(&bytes[pos..pos + digits])
+            .ok()?
+            .parse()
+            .ok()?;
+
+        args.push(number);
+        pos += digits;
+    }
+
+    expect(b')', &mut pos).then_some((args, pos))
+}
+
+fn mul(args: &[i64]) -> Option<i64> {
+    args[0].checked_mul(args[1])
+}
+
+fn part_one(program: &str) -> i64 {
+    Interpreter::new()
+        .instruction("mul", 2, mul)
+        .run(program)
+        .total
+}
+
+fn part_two(program: &str) -> i64 {
+    Interpreter::standard().run(program).total
+}
+
+fn explain(program: &str) -> Explanation {
+    let mut explanation = Explanation::new(vec!["offset", "instruction", "state", "value"]);
+
+    for step in Interpreter::standard().run(program).trace {
+        let state = if step.enabled { "enabled" } else { "disabled" };
+        let value: Field = match step.value {
+            _ if step.overflowed => "overflow".into(),
+            value => value.into(),
+        };
+
+        explanation.push(vec![
+            step.token.offset.into(),
+            step.token.to_string().into(),
+            state.into(),
+            value,
+        ]);
+    }
+
+    explanation
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> i64 {
+        part_two(input)
+    }
+
+    fn explain(&self, input: &Self::Input) -> Option<Explanation> {
+        Some(explain(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_tokenize_offsets() {
+        let tokens = Interpreter::standard().tokenize(EXAMPLE);
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| (token.offset, token.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "mul(2,4)".to_string()),
+                (20, "don't()".to_string()),
+                (28, "mul(5,5)".to_string()),
+                (48, "mul(11,8)".to_string()),
+                (59, "do()".to_string()),
+                (64, "mul(8,5)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let run = Interpreter::standard().run(EXAMPLE);
+
+        assert_eq!(run.total, 48);
+        assert_eq!(
+            run.trace
+                .iter()
+                .map(|step| (step.enabled, step.value))
+                .collect::<Vec<_>>(),
+            vec![
+                (true, Some(8)),
+                (false, None),
+                (false, None),
+                (false, None),
+                (true, None),
+                (true, Some(40)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_and_long_arguments() {
+        let interpreter = Interpreter::standard();
+
+        assert_eq!(interpreter.run("mul(1234,10000)").total, 12_340_000);
+        for program in [
+            "mul(1,2,3)",
+            "mul(1)",
+            "mul( 1,2)",
+            "mul(1,-2)",
+            "mul(99999999999999999999,1)",
+            "do(1)mul(2,3",
+        ] {
+            assert!(interpreter.tokenize(program).is_empty(), "{}", program);
+        }
+        // Garbage around an instruction, including multibyte characters,
+        // doesn't hide it
+        assert_eq!(interpreter.run("émul(mul(3,3)ü").total, 9);
+    }
+
+    #[test]
+    fn test_overflow_is_skipped() {
+        let run = Interpreter::standard().run("mul(9999999999,9999999999)mul(2,3)");
+
+        assert_eq!(run.total, 6);
+        assert!(run.trace[0].overflowed);
+        assert_eq!(run.trace[0].value, None);
+        assert!(!run.trace[1].overflowed);
+
+        // Each product fits, but the running total doesn't
+        let run =
+            Interpreter::standard().run("mul(3037000499,3037000499)mul(3037000499,3037000499)");
+        assert_eq!(run.total, 3037000499 * 3037000499);
+        assert!(run.trace[1].overflowed);
+
+        assert!(explain("mul(9999999999,9999999999)")
+            .to_csv()
+            .ends_with(",overflow\n"));
+    }
+
+    #[test]
+    fn test_explain_values_are_numbers() {
+        let json = explain("mul(2,4)don't()").to_json_lines();
+
+        assert_eq!(
+            json,
+            "{\"offset\": 0, \"instruction\": \"mul(2,4)\", \"state\": \"enabled\", \"value\": 8}\n\
+             {\"offset\": 8, \"instruction\": \"don't()\", \"state\": \"disabled\", \"value\": null}\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_instructions() {
+        let interpreter = Interpreter::standard()
+            .instruction("add", 2, |args| args[0].checked_add(args[1]))
+            .instruction("neg", 1, |args| args[0].checked_neg())
+            .instruction("sum", 3, |args| {
+                args.iter()
+                    .try_fold(0_i64, |sum, &arg| sum.checked_add(arg))
+            })
+            .conditionals("on", "off");
+
+        let run = interpreter.run("add(1,2)neg(10)off()mul(2,2)don't()on()sum(1,2,3)");
+
+        assert_eq!(run.total, 3 - 10 + 6);
+        assert_eq!(run.trace.len(), 7);
+    }
+}
@@ -0,0 +1,93 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    grid::Grid,
+    image,
+    solution::Solution,
+    stencil::Stencil,
+    word_search::{highlight, WordFinder},
+};
+
+#[derive(Debug)]
+pub struct WordSearch {
+    grid: Grid<char>,
+}
+
+/// Shows only the letters that are part of some XMAS.
+impl Display for WordSearch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letters = self.xmas_letters();
+
+        let mut s = String::new();
+        for row in letters.rows() {
+            for ch in row {
+                s.push(*ch);
+                s.push(' ');
+            }
+            s += "\n"
+        }
+
+        write!(f, "{}", s)
+    }
+}
+
+impl WordSearch {
+    /// The grid with every letter that isn't part of some XMAS blanked out.
+    fn xmas_letters(&self) -> Grid<char> {
+        let matches = WordFinder::new([XMAS]).find(&self.grid);
+
+        highlight(&self.grid, &matches, '.')
+    }
+}
+
+impl FromStr for WordSearch {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(WordSearch { grid: s.parse()? })
+    }
+}
+
+const XMAS: &str = "XMAS";
+
+const X_MAS: &str = "M.S\n\
+                     .A.\n\
+                     M.S";
+
+fn part_one(word_search: &WordSearch) -> usize {
+    WordFinder::new([XMAS]).count(&word_search.grid)
+}
+
+/// Two MAS crossing at their A, each of which may read either way, so any
+/// quarter turn of the template counts.
+fn part_two(word_search: &WordSearch) -> usize {
+    Stencil::parse(X_MAS, '.')
+        .unwrap()
+        .rotated()
+        .count(&word_search.grid)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = WordSearch;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, word_search: &Self::Input) -> usize {
+        part_one(word_search)
+    }
+
+    fn part_two(&self, word_search: &Self::Input) -> usize {
+        part_two(word_search)
+    }
+
+    /// The letters that are part of some XMAS, each letter in its own color.
+    fn render(&self, word_search: &Self::Input) -> Option<Grid<usize>> {
+        Some(image::char_indices(&word_search.xmas_letters()))
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    parse::{ParseError, Scanner},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct PageData {
+    ordering: HashMap<usize, HashSet<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl FromStr for PageData {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ordering = HashMap::<usize, HashSet<usize>>::new();
+        let mut updates = vec![];
+        let mut in_updates = false;
+
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                in_updates = true;
+                continue;
+            }
+
+            let mut scanner = Scanner::new(line);
+
+            if in_updates {
+                let update = parse_update(&mut scanner).map_err(|e| e.offset_lines(i))?;
+                updates.push(update);
+            } else {
+                let (x, y) = parse_ordering_rule(&mut scanner).map_err(|e| e.offset_lines(i))?;
+
+                ordering
+                    .entry(x)
+                    .and_modify(|s| {
+                        s.insert(y);
+                    })
+                    .or_insert(HashSet::from([y]));
+            }
+        }
+
+        if !in_updates {
+            return Err(ParseError::new(
+                s.lines().count() + 1,
+                1,
+                "",
+                "expected a blank line before the updates",
+            ));
+        }
+
+        Ok(PageData { ordering, updates })
+    }
+}
+
+fn parse_ordering_rule(scanner: &mut Scanner) -> Result<(usize, usize), ParseError> {
+    let x = scanner.number()?;
+    scanner.expect("|")?;
+    let y = scanner.number()?;
+    scanner.finish()?;
+
+    Ok((x, y))
+}
+
+fn parse_update(scanner: &mut Scanner) -> Result<Vec<usize>, ParseError> {
+    let mut update = vec![scanner.number()?];
+    while !scanner.is_done() {
+        scanner.expect(",")?;
+        update.push(scanner.number()?);
+    }
+
+    Ok(update)
+}
+
+fn part_one(data: &PageData) -> usize {
+    let mut total: usize = 0;
+
+    for update in &data.updates {
+        if update.is_sorted_by(|a, b| data.ordering.get(a).unwrap_or(&HashSet::new()).contains(b)) {
+            total += update[update.len() / 2];
+        }
+    }
+
+    total
+}
+
+fn part_two(data: &PageData) -> usize {
+    let mut total: usize = 0;
+
+    for update in &data.updates {
+        if !update.is_sorted_by(|a, b| data.ordering.get(a).unwrap_or(&HashSet::new()).contains(b))
+        {
+            let mut new_update = update.clone();
+            new_update.sort_by(|a, b| {
+                data.ordering
+                    .get(a)
+                    .unwrap_or(&HashSet::new())
+                    .contains(b)
+                    .cmp(&true)
+            });
+
+            total += new_update[new_update.len() / 2];
+        }
+    }
+
+    total
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = PageData;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(&self, data: &Self::Input) -> usize {
+        part_one(data)
+    }
+
+    fn part_two(&self, data: &Self::Input) -> usize {
+        part_two(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = "47|53\n97|13\n\n75,47,61\n97;61"
+            .parse::<PageData>()
+            .unwrap_err();
+
+        assert_eq!(err, ParseError::new(5, 3, ";61", r#"expected ",""#));
+    }
+}
@@ -0,0 +1,201 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use crate::{
+    animate::{Frame, Frames},
+    geometry::Direction,
+    grid::Grid,
+    solution::{Part, Solution},
+    visited::VisitedSet,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum GridItem {
+    Empty,
+    Obstacle,
+    SpecialObstacle,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    grid: Grid<GridItem>,
+    guard_direction: Direction,
+    guard_position: (usize, usize),
+}
+
+impl FromStr for Lab {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = Grid::parse_with(s, |ch| match ch {
+            '.' | '#' | '>' | '<' | '^' | 'v' => Ok(ch),
+            _ => bail!("Invalid grid item:  {}", ch),
+        })?;
+
+        let (guard_position, guard_direction) = chars
+            .iter()
+            .find_map(|(pos, &ch)| Some((pos, Direction::try_from(ch).ok()?)))
+            .context("Grid has no guard")?;
+
+        Ok(Lab {
+            grid: chars.map(|&ch| match ch {
+                '#' => GridItem::Obstacle,
+                _ => GridItem::Empty,
+            }),
+            guard_direction,
+            guard_position,
+        })
+    }
+}
+
+impl Display for Lab {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.chars())
+    }
+}
+
+impl Lab {
+    fn chars(&self) -> Grid<char> {
+        let mut chars = self.grid.map(|item| match item {
+            GridItem::Empty => '.',
+            GridItem::Obstacle => '#',
+            GridItem::SpecialObstacle => 'O',
+        });
+
+        chars[self.guard_position] = self.guard_direction.to_char();
+
+        chars
+    }
+
+    fn frame(&self, steps: usize, visited: usize) -> Frame {
+        Frame::new(
+            self.chars(),
+            [self.guard_position],
+            format!("step {}, {} positions visited", steps, visited),
+        )
+    }
+
+    fn move_guard(&mut self) -> Result<(usize, usize), ()> {
+        let previous_guard_position = (self.guard_position.0, self.guard_position.1);
+        let next_guard_position = self
+            .grid
+            .step(self.guard_position, self.guard_direction)
+            .ok_or(())?;
+
+        match self.grid[next_guard_position] {
+            GridItem::Empty => {
+                self.guard_position = next_guard_position;
+            }
+            GridItem::Obstacle | GridItem::SpecialObstacle => {
+                self.guard_direction = self.guard_direction.turn_right();
+
+                return Ok(previous_guard_position);
+            }
+        };
+
+        Ok(next_guard_position)
+    }
+}
+
+/// Every position the guard visits before leaving the lab.
+fn patrol(lab: &Lab) -> VisitedSet<(usize, usize)> {
+    let mut lab = lab.clone();
+    let mut seen = VisitedSet::for_grid(&lab.grid);
+    seen.insert(lab.guard_position);
+
+    while let Ok(pos) = lab.move_guard() {
+        seen.insert(pos);
+    }
+
+    seen
+}
+
+fn part_one(lab: &Lab) -> usize {
+    patrol(lab).len()
+}
+
+fn part_two(lab: &Lab) -> usize {
+    let starting_pos = lab.guard_position;
+    let mut candidate = lab.clone();
+    let mut seen = VisitedSet::for_grid(&lab.grid);
+    let mut total = 0;
+
+    for (pos, &item) in lab.grid.iter() {
+        if pos == starting_pos || item == GridItem::Obstacle {
+            continue;
+        }
+
+        // Each candidate obstacle is placed in the same grid and taken away
+        // again once the guard leaves or loops
+        candidate.grid[pos] = GridItem::SpecialObstacle;
+        candidate.guard_position = lab.guard_position;
+        candidate.guard_direction = lab.guard_direction;
+        seen.clear();
+        seen.insert((candidate.guard_position, candidate.guard_direction));
+
+        while let Ok(guard) = candidate.move_guard() {
+            if !seen.insert((guard, candidate.guard_direction)) {
+                total += 1;
+                break;
+            }
+        }
+
+        candidate.grid[pos] = GridItem::Empty;
+    }
+
+    total
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Lab;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, lab: &Self::Input) -> usize {
+        part_one(lab)
+    }
+
+    fn part_two(&self, lab: &Self::Input) -> usize {
+        part_two(lab)
+    }
+
+    /// The obstacles and every position the guard visits on patrol.
+    fn render(&self, lab: &Self::Input) -> Option<Grid<usize>> {
+        let seen = patrol(lab);
+
+        Some(Grid::from_fn(
+            lab.grid.nrows(),
+            lab.grid.ncols(),
+            |pos| match lab.grid[pos] {
+                GridItem::Obstacle | GridItem::SpecialObstacle => 1,
+                GridItem::Empty if seen.contains(pos) => 2,
+                GridItem::Empty => 0,
+            },
+        ))
+    }
+
+    /// The guard's patrol, which is the same walk for both parts.
+    fn animate(&self, mut lab: Self::Input, _part: Part) -> Option<Frames> {
+        let mut seen = VisitedSet::for_grid(&lab.grid);
+        seen.insert(lab.guard_position);
+        let mut steps = 0;
+        let first = lab.frame(steps, seen.len());
+
+        let rest = std::iter::from_fn(move || {
+            let pos = lab.move_guard().ok()?;
+            seen.insert(pos);
+            steps += 1;
+
+            Some(lab.frame(steps, seen.len()))
+        });
+
+        Some(Box::new(std::iter::once(first).chain(rest)))
+    }
+}
@@ -1,21 +1,32 @@
 use std::{fmt::Display, str::FromStr};
 
+use crate::{
+    parse::{ParseError, Scanner},
+    solution::Solution,
+};
+
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     target: isize,
     operands: Vec<isize>,
 }
 
 impl FromStr for Equation {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (target_str, list_str) = s.split_once(": ").unwrap();
+        let mut scanner = Scanner::new(s);
 
-        Ok(Equation {
-            target: target_str.parse().unwrap(),
-            operands: list_str.split(" ").map(|s| s.parse().unwrap()).collect(),
-        })
+        let target = scanner.number()?;
+        scanner.expect(": ")?;
+
+        let mut operands = vec![scanner.number()?];
+        while !scanner.is_done() {
+            scanner.expect(" ")?;
+            operands.push(scanner.number()?);
+        }
+
+        Ok(Equation { target, operands })
     }
 }
 
@@ -91,14 +102,17 @@ impl Equation {
     }
 }
 
-fn part_one(input: &str) -> isize {
+fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
     input
         .lines()
-        .map(|line| {
-            line.trim()
-                .parse::<Equation>()
-                .expect("Unable to parse equation")
-        })
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: ParseError| e.offset_lines(i)))
+        .collect()
+}
+
+fn part_one(equations: &[Equation]) -> isize {
+    equations
+        .iter()
         .filter_map(|e| {
             if e.is_possible(false) {
                 Some(e.target)
@@ -109,14 +123,9 @@ fn part_one(input: &str) -> isize {
         .sum()
 }
 
-fn part_two(input: &str) -> isize {
-    input
-        .lines()
-        .map(|line| {
-            line.trim()
-                .parse::<Equation>()
-                .expect("Unable to parse equation")
-        })
+fn part_two(equations: &[Equation]) -> isize {
+    equations
+        .iter()
         .filter_map(|e| {
             if e.is_possible(true) {
                 Some(e.target)
@@ -127,11 +136,24 @@ fn part_two(input: &str) -> isize {
         .sum()
 }
 
-fn main() {
-    let input = include_str!("../../inputs/day7.txt");
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type PartOne = isize;
+    type PartTwo = isize;
 
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_equations(input)?)
+    }
+
+    fn part_one(&self, equations: &Self::Input) -> isize {
+        part_one(equations)
+    }
+
+    fn part_two(&self, equations: &Self::Input) -> isize {
+        part_two(equations)
+    }
 }
 
 #[cfg(test)]
@@ -169,36 +191,11 @@ mod tests {
     }
 
     #[test]
-    fn test_part_one_d7() {
-        let input = r"190: 10 19
-                      3267: 81 40 27
-                      83: 17 5
-                      156: 15 6
-                      7290: 6 8 6 15
-                      161011: 16 10 13
-                      192: 17 8 14
-                      21037: 9 7 18 13
-                      292: 11 6 16 20";
-
-        let res = part_one(input);
-
-        assert_eq!(res, 3749)
-    }
+    fn test_parse_error() {
+        let err = parse_equations("190: 10 19\n3267: 81 4O 27").unwrap_err();
 
-    #[test]
-    fn test_part_two_d7() {
-        let input = r"190: 10 19
-                      3267: 81 40 27
-                      83: 17 5
-                      156: 15 6
-                      7290: 6 8 6 15
-                      161011: 16 10 13
-                      192: 17 8 14
-                      21037: 9 7 18 13
-                      292: 11 6 16 20";
-
-        let res = part_two(input);
-
-        assert_eq!(res, 11387)
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 11);
+        assert_eq!(err.text, "O");
     }
 }
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    grid::Grid,
+    image,
+    solution::{Solution, Unsolved},
+    visited::VisitedSet,
+};
+
+#[derive(Debug)]
+enum GridItem {
+    Blank,
+    Antenna(u8),
+}
+
+#[derive(Debug)]
+pub struct AntennaMap {
+    grid: Grid<GridItem>,
+}
+
+impl FromStr for AntennaMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |ch| {
+            Ok(match ch {
+                '.' => GridItem::Blank,
+                _ => GridItem::Antenna(ch as u8),
+            })
+        })?;
+
+        Ok(AntennaMap { grid })
+    }
+}
+
+impl AntennaMap {
+    fn get_frequency_map(&self) -> HashMap<u8, Vec<(usize, usize)>> {
+        let mut frequency_map = HashMap::<u8, Vec<(usize, usize)>>::new();
+        for (pos, item) in self.grid.iter() {
+            match item {
+                GridItem::Blank => continue,
+                GridItem::Antenna(freq) => {
+                    let coords = frequency_map.entry(*freq).or_default();
+                    coords.push(pos);
+                }
+            }
+        }
+
+        frequency_map
+    }
+
+    fn get_antinode(
+        &self,
+        first_antenna: (usize, usize),
+        second_antenna: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let (x1, y1) = first_antenna;
+        let (x2, y2) = second_antenna;
+
+        let newx = x2 as isize + (x2 as isize - x1 as isize);
+        let newy = y2 as isize + (y2 as isize - y1 as isize);
+
+        if newx >= 0
+            && newx < self.grid.nrows() as isize
+            && newy < self.grid.ncols() as isize
+            && newy >= 0
+        {
+            return Some((newx as usize, newy as usize));
+        }
+
+        None
+    }
+}
+
+/// Every antinode in the map, ignoring resonant harmonics.
+fn antinodes(antenna_map: &AntennaMap) -> VisitedSet<(usize, usize)> {
+    let mut antinode_coords = VisitedSet::for_grid(&antenna_map.grid);
+    let frequency_map = antenna_map.get_frequency_map();
+
+    for coords in frequency_map.values() {
+        for i in 0..coords.len() {
+            for j in 0..i {
+                let node1 = coords[i];
+                let node2 = coords[j];
+
+                if let Some((x1, y1)) = antenna_map.get_antinode(node1, node2) {
+                    antinode_coords.insert((x1, y1));
+                }
+
+                if let Some((x2, y2)) = antenna_map.get_antinode(node2, node1) {
+                    antinode_coords.insert((x2, y2));
+                }
+            }
+        }
+    }
+
+    antinode_coords
+}
+
+fn part_one(antenna_map: &AntennaMap) -> usize {
+    antinodes(antenna_map).len()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = AntennaMap;
+    type PartOne = usize;
+    type PartTwo = Unsolved;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(&self, antenna_map: &Self::Input) -> usize {
+        part_one(antenna_map)
+    }
+
+    fn part_two(&self, _antenna_map: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+
+    /// The antennas, a color per frequency, over part one's antinodes.
+    fn render(&self, antenna_map: &Self::Input) -> Option<Grid<usize>> {
+        let antinodes = antinodes(antenna_map);
+        let antennas = image::char_indices(&antenna_map.grid.map(|item| match item {
+            GridItem::Blank => '.',
+            GridItem::Antenna(freq) => *freq as char,
+        }));
+
+        Some(Grid::from_fn(
+            antennas.nrows(),
+            antennas.ncols(),
+            |pos| match antennas[pos] {
+                0 if antinodes.contains(pos) => 1,
+                0 => 0,
+                antenna => antenna + 1,
+            },
+        ))
+    }
+}
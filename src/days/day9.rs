@@ -1,17 +1,21 @@
+use anyhow::Context;
+
+use crate::solution::Solution;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum DiskMapItemKind {
     File,
     FreeSpace,
 }
 
-#[derive(Debug)]
-struct DiskMapItem {
+#[derive(Debug, Clone)]
+pub struct DiskMapItem {
     kind: DiskMapItemKind,
     id: Option<usize>,
     size: u32,
 }
 
-fn get_disk_map(input: &str) -> Vec<DiskMapItem> {
+fn get_disk_map(input: &str) -> anyhow::Result<Vec<DiskMapItem>> {
     let mut disk_map: Vec<DiskMapItem> = vec![];
 
     let mut id: usize = 0;
@@ -27,7 +31,9 @@ fn get_disk_map(input: &str) -> Vec<DiskMapItem> {
             None
         };
 
-        let size = ch.to_digit(10).expect("Char was not 0-9 {}");
+        let size = ch
+            .to_digit(10)
+            .with_context(|| format!("Char was not 0-9 {}", ch))?;
 
         for _ in 0..size {
             disk_map.push(DiskMapItem {
@@ -42,11 +48,11 @@ fn get_disk_map(input: &str) -> Vec<DiskMapItem> {
         }
     }
 
-    disk_map
+    Ok(disk_map)
 }
 
-fn part_one(input: &str) -> usize {
-    let mut disk_map = get_disk_map(input);
+fn part_one(disk_map: &[DiskMapItem]) -> usize {
+    let mut disk_map = disk_map.to_vec();
 
     let mut start_ptr = 0;
     let mut end_ptr = disk_map.len() - 1;
@@ -81,8 +87,8 @@ fn part_one(input: &str) -> usize {
         .sum::<usize>()
 }
 
-fn part_two(input: &str) -> usize {
-    let mut disk_map = get_disk_map(input);
+fn part_two(disk_map: &[DiskMapItem]) -> usize {
+    let mut disk_map = disk_map.to_vec();
 
     let mut end_ptr = disk_map.len() - 1;
 
@@ -132,30 +138,22 @@ fn part_two(input: &str) -> usize {
         .sum::<usize>()
 }
 
-fn main() {
-    let input = include_str!("../../inputs/day9.txt");
+pub struct Day9;
 
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
-}
+impl Solution for Day9 {
+    type Input = Vec<DiskMapItem>;
+    type PartOne = usize;
+    type PartTwo = usize;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one_d9() {
-        let input = r"2333133121414131402";
-        let res = part_one(input);
-
-        assert_eq!(res, 1928)
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        get_disk_map(input)
     }
 
-    #[test]
-    fn test_part_two_d9() {
-        let input = r"2333133121414131402";
-        let res = part_two(input);
+    fn part_one(&self, disk_map: &Self::Input) -> usize {
+        part_one(disk_map)
+    }
 
-        assert_eq!(res, 2858)
+    fn part_two(&self, disk_map: &Self::Input) -> usize {
+        part_two(disk_map)
     }
 }
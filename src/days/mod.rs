@@ -0,0 +1,114 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::solution::DynSolution;
+
+/// A single puzzle day and the solver registered for it.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Day {
+    pub fn example_solution(&self) -> &'static dyn DynSolution {
+        EXAMPLE_SOLUTIONS
+            .iter()
+            .find(|day| day.number == self.number)
+            .unwrap_or(self)
+            .solution
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &day1::Day1,
+    },
+    Day {
+        number: 2,
+        solution: &day2::Day2,
+    },
+    Day {
+        number: 3,
+        solution: &day3::Day3,
+    },
+    Day {
+        number: 4,
+        solution: &day4::Day4,
+    },
+    Day {
+        number: 5,
+        solution: &day5::Day5,
+    },
+    Day {
+        number: 6,
+        solution: &day6::Day6,
+    },
+    Day {
+        number: 7,
+        solution: &day7::Day7,
+    },
+    Day {
+        number: 8,
+        solution: &day8::Day8,
+    },
+    Day {
+        number: 9,
+        solution: &day9::Day9,
+    },
+    Day {
+        number: 10,
+        solution: &day10::Day10,
+    },
+    Day {
+        number: 11,
+        solution: &day11::Day11,
+    },
+    Day {
+        number: 12,
+        solution: &day12::Day12,
+    },
+    Day {
+        number: 13,
+        solution: &day13::Day13,
+    },
+    Day {
+        number: 14,
+        solution: &day14::Day14 {
+            width: 101,
+            height: 103,
+        },
+    },
+    Day {
+        number: 15,
+        solution: &day15::Day15,
+    },
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Solvers to run against the examples for days whose examples are configured
+/// differently from the real puzzle.
+static EXAMPLE_SOLUTIONS: &[Day] = &[Day {
+    number: 14,
+    solution: &day14::Day14 {
+        width: 11,
+        height: 7,
+    },
+}];
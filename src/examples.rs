@@ -0,0 +1,141 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{ensure, Context};
+
+use crate::{answers::Answers, solution::Part};
+
+/// Example fixtures live in `examples/dayN/`, as `<name>.txt` holding the
+/// example input and `<name>.answers` holding the expected answers, in the
+/// same format as `answers.toml`:
+///
+/// ```toml
+/// [day5]
+/// part_one = "143"
+/// part_two = "123"
+/// ```
+///
+/// Either part may be left out when the puzzle gives no answer for it.
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+pub fn day_dir(day: u8) -> PathBuf {
+    examples_dir().join(format!("day{}", day))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Example {
+    pub fn load(day: u8, name: &str) -> anyhow::Result<Self> {
+        let dir = day_dir(day);
+        let input_path = dir.join(format!("{}.txt", name));
+        let answers_path = dir.join(format!("{}.answers", name));
+
+        let input = std::fs::read_to_string(&input_path)
+            .with_context(|| format!("Failed to read {}", input_path.display()))?;
+        let answers = std::fs::read_to_string(&answers_path)
+            .with_context(|| format!("Failed to read {}", answers_path.display()))?
+            .parse::<Answers>()
+            .with_context(|| format!("Failed to parse {}", answers_path.display()))?;
+
+        ensure!(
+            answers.days().all(|number| number == day),
+            "{} has answers for a day other than {}",
+            answers_path.display(),
+            day
+        );
+
+        Ok(Example {
+            name: name.to_string(),
+            input,
+            part_one: answers.get(day, Part::One).map(str::to_string),
+            part_two: answers.get(day, Part::Two).map(str::to_string),
+        })
+    }
+
+    /// Loads every example for `day`, in name order. A day without an
+    /// examples directory has none.
+    pub fn load_all(day: u8) -> anyhow::Result<Vec<Self>> {
+        let dir = day_dir(day);
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut names = vec![];
+        for entry in
+            std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+
+        names.iter().map(|name| Example::load(day, name)).collect()
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    /// Runs every fixture under `examples/` through its day's solver.
+    #[test]
+    fn test_examples() {
+        let mut failures = vec![];
+
+        for day in DAYS {
+            let examples = Example::load_all(day.number).unwrap();
+            if examples.is_empty() {
+                failures.push(format!("day {}: no examples", day.number));
+            }
+
+            for example in &examples {
+                for part in Part::BOTH {
+                    let Some(expected) = example.expected(part) else {
+                        continue;
+                    };
+
+                    match day.example_solution().solve(&example.input, part) {
+                        Ok(answer) if answer == expected => {}
+                        Ok(answer) => failures.push(format!(
+                            "day {} {} part {}: expected {}, got {}",
+                            day.number, example.name, part, expected, answer
+                        )),
+                        Err(e) => failures.push(format!(
+                            "day {} {} part {}: {:#}",
+                            day.number, example.name, part, e
+                        )),
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_load_fixture_answers() {
+        let example = Example::load(12, "diagonal").unwrap();
+
+        assert_eq!(example.expected(Part::One), None);
+        assert_eq!(example.expected(Part::Two), Some("368"));
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt::Display;
+
+use crate::runner::{csv_field, json_string};
+
+/// One cell of an [`Explanation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Field {
+    Text(String),
+    Number(i64),
+    /// Nothing to report, like the failing index of a safe report.
+    Missing,
+}
+
+impl Field {
+    fn to_json(&self) -> String {
+        match self {
+            Field::Text(text) => json_string(text),
+            Field::Number(n) => n.to_string(),
+            Field::Missing => "null".to_string(),
+        }
+    }
+}
+
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Field::Text(text) => f.pad(text),
+            Field::Number(n) => f.pad(&n.to_string()),
+            Field::Missing => f.pad("-"),
+        }
+    }
+}
+
+impl From<&str> for Field {
+    fn from(text: &str) -> Self {
+        Field::Text(text.to_string())
+    }
+}
+
+impl From<String> for Field {
+    fn from(text: String) -> Self {
+        Field::Text(text)
+    }
+}
+
+impl From<usize> for Field {
+    fn from(n: usize) -> Self {
+        Field::Number(
+            n.try_into()
+                .expect("Number is too large for an explanation"),
+        )
+    }
+}
+
+impl From<i64> for Field {
+    fn from(n: i64) -> Self {
+        Field::Number(n)
+    }
+}
+
+impl<T: Into<Field>> From<Option<T>> for Field {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Field::Missing, Into::into)
+    }
+}
+
+/// A per-item breakdown of how a day reached its answers, such as why each
+/// report is or isn't safe, for `aoc explain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    columns: Vec<String>,
+    rows: Vec<Vec<Field>>,
+}
+
+impl Explanation {
+    pub fn new<S: Into<String>>(columns: Vec<S>) -> Self {
+        Explanation {
+            columns: columns.into_iter().map(Into::into).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<Field>) {
+        assert_eq!(
+            row.len(),
+            self.columns.len(),
+            "Row needs one field per column"
+        );
+
+        self.rows.push(row);
+    }
+
+    pub fn rows(&self) -> &[Vec<Field>] {
+        &self.rows
+    }
+
+    /// Renders an aligned table with a header row.
+    pub fn to_table(&self) -> String {
+        let widths = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                self.rows
+                    .iter()
+                    .map(|row| row[i].to_string().chars().count())
+                    .chain(std::iter::once(column.len()))
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let line = |fields: Vec<String>| {
+            let cells = fields
+                .iter()
+                .zip(&widths)
+                .map(|(field, &width)| format!("{:<width$}", field))
+                .collect::<Vec<_>>();
+
+            cells.join("  ").trim_end().to_string() + "\n"
+        };
+
+        let mut res = line(self.columns.iter().map(|c| c.to_string()).collect());
+        res += &format!(
+            "{}\n",
+            "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1))
+        );
+        for row in &self.rows {
+            res += &line(row.iter().map(|field| field.to_string()).collect());
+        }
+
+        res
+    }
+
+    /// Renders one JSON object per row, keyed by column name, with missing
+    /// fields as `null`.
+    pub fn to_json_lines(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                let fields = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, field)| format!("{}: {}", json_string(column), field.to_json()))
+                    .collect::<Vec<_>>();
+
+                format!("{{{}}}\n", fields.join(", "))
+            })
+            .collect()
+    }
+
+    /// Renders CSV with a header row, with missing fields left empty.
+    pub fn to_csv(&self) -> String {
+        let mut res = self.columns.join(",") + "\n";
+
+        for row in &self.rows {
+            let fields = row
+                .iter()
+                .map(|field| match field {
+                    Field::Missing => String::new(),
+                    field => csv_field(&field.to_string()),
+                })
+                .collect::<Vec<_>>();
+
+            res += &(fields.join(",") + "\n");
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explanation() -> Explanation {
+        let mut explanation = Explanation::new(vec!["item", "verdict", "delta"]);
+        explanation.push(vec![1_usize.into(), "safe".into(), None::<i64>.into()]);
+        explanation.push(vec![
+            2_usize.into(),
+            "unsafe, \"bad\"".into(),
+            Some(-12_i64).into(),
+        ]);
+
+        explanation
+    }
+
+    #[test]
+    fn test_to_table() {
+        assert_eq!(
+            explanation().to_table(),
+            "item  verdict        delta\n\
+             --------------------------\n\
+             1     safe           -\n\
+             2     unsafe, \"bad\"  -12\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json_lines_and_csv() {
+        assert_eq!(
+            explanation().to_json_lines(),
+            "{\"item\": 1, \"verdict\": \"safe\", \"delta\": null}\n\
+             {\"item\": 2, \"verdict\": \"unsafe, \\\"bad\\\"\", \"delta\": -12}\n"
+        );
+        assert_eq!(
+            explanation().to_csv(),
+            "item,verdict,delta\n1,safe,\n2,\"unsafe, \"\"bad\"\"\",-12\n"
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed point or offset on a grid, with `row` growing downward and `col`
+/// growing rightward, matching `Grid`'s `(row, col)` positions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    /// The grid position of this point, if neither coordinate is negative.
+    pub fn to_pos(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+
+    /// This point moved into the `nrows` by `ncols` rectangle at the origin by
+    /// wrapping around its edges, as on a torus.
+    pub fn wrap(self, nrows: usize, ncols: usize) -> Point {
+        Point::new(
+            self.row.rem_euclid(nrows as isize),
+            self.col.rem_euclid(ncols as isize),
+        )
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as isize, col as isize)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((row, col): (isize, isize)) -> Self {
+        Point::new(row, col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Point::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Every direction, in the same order `Grid::neighbors` visits them.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+            Direction::Right => Point::new(0, 1),
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Turns 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    /// Parses one of `^`, `v`, `<` or `>`, handing back any other character.
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            '^' => Ok(Direction::Up),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            _ => Err(ch),
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Self {
+        dir.delta()
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction8::North => Point::new(-1, 0),
+            Direction8::NorthEast => Point::new(-1, 1),
+            Direction8::East => Point::new(0, 1),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(1, 0),
+            Direction8::SouthWest => Point::new(1, -1),
+            Direction8::West => Point::new(0, -1),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Direction8::North,
+            Direction::Down => Direction8::South,
+            Direction::Left => Direction8::West,
+            Direction::Right => Direction8::East,
+        }
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(dir: Direction8) -> Self {
+        dir.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let p = Point::new(2, 3);
+
+        assert_eq!(p + Direction::Up.delta(), Point::new(1, 3));
+        assert_eq!(p - Point::new(3, 1), Point::new(-1, 2));
+        assert_eq!(-p * 2, Point::new(-4, -6));
+        assert_eq!(p.manhattan(Point::new(-1, 5)), 5);
+        assert_eq!(Point::new(-1, 0).to_pos(), None);
+        assert_eq!(Point::from((4_usize, 5_usize)).to_pos(), Some((4, 5)));
+        assert_eq!(Point::new(-1, 7).wrap(3, 5), Point::new(2, 2));
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point::ORIGIN);
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn test_turns8() {
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point::ORIGIN);
+            assert_eq!(
+                dir.is_diagonal(),
+                dir.delta().row != 0 && dir.delta().col != 0
+            );
+        }
+
+        assert_eq!(Direction8::NorthWest.turn_right(), Direction8::North);
+        assert_eq!(
+            Direction8::from(Direction::Left).delta(),
+            Direction::Left.delta()
+        );
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        let dirs = "^>v<"
+            .chars()
+            .map(Direction::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            dirs,
+            vec![
+                Direction::Up,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left
+            ]
+        );
+        assert_eq!(
+            dirs.iter().map(|dir| dir.to_char()).collect::<String>(),
+            "^>v<"
+        );
+        assert_eq!(Direction::try_from('x'), Err('x'));
+    }
+}
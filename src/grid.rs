@@ -0,0 +1,586 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{bail, ensure};
+
+use crate::geometry::{Direction, Point};
+
+/// A dense 2D grid stored row-major, addressed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    nrows: usize,
+    ncols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(nrows: usize, ncols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            nrows,
+            ncols,
+            cells: vec![fill; nrows * ncols],
+        }
+    }
+
+    pub fn from_vec(nrows: usize, ncols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            nrows * ncols,
+            "Grid of {}x{} needs {} cells",
+            nrows,
+            ncols,
+            nrows * ncols
+        );
+
+        Grid {
+            nrows,
+            ncols,
+            cells,
+        }
+    }
+
+    /// Builds a grid by calling `f` with each position, in row-major order.
+    pub fn from_fn<F>(nrows: usize, ncols: usize, mut f: F) -> Self
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        let cells = (0..nrows)
+            .flat_map(|row| (0..ncols).map(move |col| (row, col)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            nrows,
+            ncols,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, converting each character with `parse_cell`.
+    pub fn parse_with<F>(s: &str, mut parse_cell: F) -> anyhow::Result<Self>
+    where
+        F: FnMut(char) -> anyhow::Result<T>,
+    {
+        let mut nrows = 0;
+        let mut ncols = 0;
+        let mut cells = vec![];
+
+        for (row, line) in s.lines().enumerate() {
+            let len_before = cells.len();
+            for ch in line.chars() {
+                cells.push(parse_cell(ch)?);
+            }
+
+            let row_len = cells.len() - len_before;
+            if row == 0 {
+                ncols = row_len;
+            } else {
+                ensure!(
+                    row_len == ncols,
+                    "Row {} has {} columns, expected {}",
+                    row + 1,
+                    row_len,
+                    ncols
+                );
+            }
+
+            nrows += 1;
+        }
+
+        if nrows == 0 || ncols == 0 {
+            bail!("Grid is empty");
+        }
+
+        Ok(Grid {
+            nrows,
+            ncols,
+            cells,
+        })
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    pub fn in_bounds(&self, (row, col): (usize, usize)) -> bool {
+        row < self.nrows && col < self.ncols
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.cells[pos.0 * self.ncols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.ncols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.ncols..(row + 1) * self.ncols]
+    }
+
+    /// Every row, top to bottom. A grid with no columns still has `nrows`
+    /// empty rows.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.nrows).map(|row| self.row(row))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.ncols, "Column {} out of bounds", col);
+
+        self.cells.iter().skip(col).step_by(self.ncols)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.ncols).map(|col| self.col(col))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let ncols = self.ncols;
+
+        (0..self.nrows).flat_map(move |row| (0..ncols).map(move |col| (row, col)))
+    }
+
+    /// Every cell along with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position one step from `pos` by `delta`, if it is still in bounds.
+    pub fn step(&self, pos: (usize, usize), delta: impl Into<Point>) -> Option<(usize, usize)> {
+        let next = (Point::from(pos) + delta.into()).to_pos()?;
+
+        self.in_bounds(next).then_some(next)
+    }
+
+    /// The position `pos` lands on when the grid repeats forever in every
+    /// direction, as on a torus. Panics if the grid has no cells, since there
+    /// is nowhere to land.
+    pub fn wrap(&self, pos: impl Into<Point>) -> (usize, usize) {
+        assert!(
+            self.nrows > 0 && self.ncols > 0,
+            "Can't wrap onto an empty {}x{} grid",
+            self.nrows,
+            self.ncols
+        );
+
+        let Point { row, col } = pos.into().wrap(self.nrows, self.ncols);
+
+        (row as usize, col as usize)
+    }
+
+    /// In-bounds orthogonal neighbors, in up, down, left, right order.
+    pub fn neighbors(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Position of the first cell, in row-major order, matching `predicate`.
+    pub fn position<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.ncols, self.nrows, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.ncols, self.nrows, |(row, col)| {
+            self[(self.nrows - 1 - col, row)].clone()
+        })
+    }
+
+    /// The grid turned a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.ncols, self.nrows, |(row, col)| {
+            self[(col, self.ncols - 1 - row)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.nrows, self.ncols, |(row, col)| {
+            self[(row, self.ncols - 1 - col)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.nrows, self.ncols, |(row, col)| {
+            self[(self.nrows - 1 - row, col)].clone()
+        })
+    }
+
+    /// The grid repeated `down` times vertically and `across` times horizontally.
+    pub fn tile(&self, down: usize, across: usize) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.nrows * down, self.ncols * across, |(row, col)| {
+            self[(row % self.nrows, col % self.ncols)].clone()
+        })
+    }
+
+    /// A borrowed `nrows` by `ncols` window whose top-left corner is at
+    /// `origin`, if it fits inside the grid.
+    pub fn view(
+        &self,
+        origin: (usize, usize),
+        nrows: usize,
+        ncols: usize,
+    ) -> Option<GridView<'_, T>> {
+        let fits = origin.0 + nrows <= self.nrows && origin.1 + ncols <= self.ncols;
+
+        fits.then_some(GridView {
+            grid: self,
+            origin,
+            nrows,
+            ncols,
+        })
+    }
+
+    /// Every `nrows` by `ncols` window of the grid, with top-left corners in
+    /// row-major order.
+    pub fn windows(&self, nrows: usize, ncols: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        // Windows larger than the grid have no origins at all
+        let rows = 0..(self.nrows + 1).saturating_sub(nrows);
+        let cols = 0..(self.ncols + 1).saturating_sub(ncols);
+
+        rows.flat_map(move |row| {
+            cols.clone().map(move |col| GridView {
+                grid: self,
+                origin: (row, col),
+                nrows,
+                ncols,
+            })
+        })
+    }
+
+    /// Renders the grid one line per row using `to_char` for each cell.
+    pub fn display_with<F>(&self, to_char: F) -> GridDisplay<'_, T, F>
+    where
+        F: Fn(&T) -> char,
+    {
+        GridDisplay {
+            grid: self,
+            to_char,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is out of bounds", pos))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is out of bounds", pos))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, Ok)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.display_with(|&ch| ch).fmt(f)
+    }
+}
+
+/// A rectangular window into a [`Grid`], addressed from its own top-left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (usize, usize),
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// Where the view's top-left corner sits in the underlying grid.
+    pub fn origin(&self) -> (usize, usize) {
+        self.origin
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&'a T> {
+        if row < self.nrows && col < self.ncols {
+            self.grid.get((self.origin.0 + row, self.origin.1 + col))
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (grid, (top, left), ncols) = (self.grid, self.origin, self.ncols);
+
+        (top..top + self.nrows).map(move |row| &grid.row(row)[left..left + ncols])
+    }
+
+    /// Every cell along with its position in the view, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| ((row, col), cell))
+        })
+    }
+
+    /// Copies the view out into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.nrows, self.ncols, |pos| self[pos].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is out of bounds", pos))
+    }
+}
+
+pub struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    to_char: F,
+}
+
+impl<T, F> Display for GridDisplay<'_, T, F>
+where
+    F: Fn(&T) -> char,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut res = String::with_capacity((self.grid.ncols + 1) * self.grid.nrows);
+        for row in self.grid.rows() {
+            res.extend(row.iter().map(&self.to_char));
+            res.push('\n');
+        }
+
+        f.write_str(&res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction8;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = "#..\n\
+                    .@.\n\
+                    ..#"
+        .parse::<Grid<char>>()
+        .unwrap();
+
+        assert_eq!(grid.nrows(), 3);
+        assert_eq!(grid.ncols(), 3);
+        assert_eq!(grid[(1, 1)], '@');
+        assert_eq!(grid.position(|&ch| ch == '@'), Some((1, 1)));
+        assert_eq!(grid.to_string(), "#..\n.@.\n..#\n");
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert!("...\n..".parse::<Grid<char>>().is_err());
+        assert!("".parse::<Grid<char>>().is_err());
+        // Leading spaces are cells, so an indented row is longer than the rest
+        assert!("..\n  ..".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = Grid::new(2, 3, 0);
+
+        assert_eq!(grid.get((1, 2)), Some(&0));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let grid = Grid::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.col(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.cols().count(), 3);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(
+            grid.neighbors((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors((1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let grid = Grid::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(
+            grid.transpose(),
+            Grid::from_vec(3, 2, vec![1, 4, 2, 5, 3, 6])
+        );
+        assert_eq!(
+            grid.rotate_right(),
+            Grid::from_vec(3, 2, vec![4, 1, 5, 2, 6, 3])
+        );
+        assert_eq!(
+            grid.rotate_left(),
+            Grid::from_vec(3, 2, vec![3, 6, 2, 5, 1, 4])
+        );
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(
+            grid.flip_horizontal(),
+            Grid::from_vec(2, 3, vec![3, 2, 1, 6, 5, 4])
+        );
+        assert_eq!(
+            grid.flip_vertical(),
+            Grid::from_vec(2, 3, vec![4, 5, 6, 1, 2, 3])
+        );
+        assert_eq!(
+            grid.rotate_right().rotate_right(),
+            grid.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::from_fn(3, 4, |(row, col)| row * 4 + col);
+
+        let view = grid.view((1, 1), 2, 2).unwrap();
+        assert_eq!(view[(0, 0)], 5);
+        assert_eq!(view.get((1, 1)), Some(&10));
+        assert_eq!(view.get((0, 2)), None);
+        assert_eq!(view.to_grid(), Grid::from_vec(2, 2, vec![5, 6, 9, 10]));
+        assert!(grid.view((2, 0), 2, 1).is_none());
+
+        let origins = grid
+            .windows(2, 3)
+            .map(|view| view.origin())
+            .collect::<Vec<_>>();
+        assert_eq!(origins, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.windows(4, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_tile_and_wrap() {
+        let grid = Grid::from_vec(2, 2, vec![1, 2, 3, 4]);
+
+        let tiled = grid.tile(2, 3);
+        assert_eq!((tiled.nrows(), tiled.ncols()), (4, 6));
+        assert_eq!(tiled.row(3), &[3, 4, 3, 4, 3, 4]);
+
+        assert_eq!(grid.wrap(Point::new(-1, 5)), (1, 1));
+        assert_eq!(grid.wrap((2_isize, -2_isize)), (0, 0));
+    }
+
+    #[test]
+    fn test_zero_width_grid() {
+        let grid = Grid::<u8>::new(3, 0, 0);
+
+        assert_eq!(grid.rows().count(), 3);
+        assert!(grid.rows().all(<[u8]>::is_empty));
+        assert_eq!(grid.windows(1, 1).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty")]
+    fn test_wrap_empty_grid() {
+        Grid::<u8>::new(0, 0, 0).wrap((1_isize, 1_isize));
+    }
+
+    #[test]
+    fn test_step() {
+        let grid = Grid::new(2, 3, ());
+
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.step((1, 2), Direction8::SouthEast), None);
+        assert_eq!(grid.step((1, 2), Point::new(-1, -2)), Some((0, 0)));
+    }
+}
@@ -0,0 +1,339 @@
+use std::{fmt::Display, path::Path, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl FromStr for Rgb {
+    type Err = anyhow::Error;
+
+    /// Parses a `#rrggbb` hex color.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix('#')
+            .filter(|hex| hex.len() == 6 && hex.is_ascii())
+            .with_context(|| format!("Expected a color like #ff8800, found {:?}", s))?;
+
+        let channel = |i: usize| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .with_context(|| format!("Invalid hex color {:?}", s))
+        };
+
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Colors for rendering cells by index. Index 0 is the background, and
+/// indices past the end wrap around to the first non-background color, so any
+/// number of labels can be drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Rgb>,
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Rgb>) -> Self {
+        assert!(!colors.is_empty(), "Palette needs at least one color");
+
+        Palette { colors }
+    }
+
+    pub fn color(&self, index: usize) -> Rgb {
+        match index {
+            0 => self.colors[0],
+            _ if self.colors.len() == 1 => self.colors[0],
+            _ => self.colors[1 + (index - 1) % (self.colors.len() - 1)],
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new(vec![
+            Rgb(0x10, 0x10, 0x18),
+            Rgb(0x4c, 0xaf, 0x50),
+            Rgb(0xe5, 0x39, 0x35),
+            Rgb(0x1e, 0x88, 0xe5),
+            Rgb(0xfd, 0xd8, 0x35),
+            Rgb(0x8e, 0x24, 0xaa),
+            Rgb(0x00, 0xac, 0xc1),
+            Rgb(0xfb, 0x8c, 0x00),
+            Rgb(0xd8, 0x1b, 0x60),
+            Rgb(0x7c, 0xb3, 0x42),
+            Rgb(0x5e, 0x35, 0xb1),
+            Rgb(0xf4, 0xf4, 0xf4),
+        ])
+    }
+}
+
+impl FromStr for Palette {
+    type Err = anyhow::Error;
+
+    /// Parses comma-separated `#rrggbb` colors, background first.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let colors = s
+            .split(',')
+            .map(|color| color.trim().parse())
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Palette::new(colors))
+    }
+}
+
+/// An RGB image, stored row-major.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each grid cell as a `cell_size` by `cell_size` square colored by `color`.
+    pub fn from_grid<T, F>(grid: &Grid<T>, cell_size: usize, color: F) -> Self
+    where
+        F: Fn(&T) -> Rgb,
+    {
+        assert!(cell_size > 0, "Cell size must be at least 1");
+
+        let width = grid.ncols() * cell_size;
+        let height = grid.nrows() * cell_size;
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.rows() {
+            let line = row
+                .iter()
+                .flat_map(|cell| std::iter::repeat_n(color(cell), cell_size))
+                .collect::<Vec<_>>();
+
+            for _ in 0..cell_size {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Draws a grid of palette indices, such as region labels or counts.
+    pub fn from_indices(grid: &Grid<usize>, cell_size: usize, palette: &Palette) -> Self {
+        Image::from_grid(grid, cell_size, |&index| palette.color(index))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Encodes the image as a binary (P6) PPM.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut res = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        res.extend(self.pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+
+        res
+    }
+
+    /// Encodes the image as an 8-bit RGB PNG. The image data is stored
+    /// uncompressed, which keeps the encoder tiny at the cost of file size.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.height * (1 + self.width * 3));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            // Filter type 0, each scanline stored as is
+            raw.push(0);
+            raw.extend(row.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]));
+        }
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, truecolor, deflate, no filter method, no interlace
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut res = b"\x89PNG\r\n\x1a\n".to_vec();
+        write_chunk(&mut res, b"IHDR", &header);
+        write_chunk(&mut res, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut res, b"IEND", &[]);
+
+        res
+    }
+
+    /// Writes the image as PNG or PPM, depending on the file extension.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => bail!(
+                "Unsupported image format for {}, use .png or .ppm",
+                path.display()
+            ),
+        };
+
+        std::fs::write(path, bytes).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Maps each distinct character of a grid to its own palette index, with `.`
+/// as the background, so any puzzle map can be drawn as is.
+pub fn char_indices(grid: &Grid<char>) -> Grid<usize> {
+    let mut chars = grid
+        .iter()
+        .map(|(_, &ch)| ch)
+        .filter(|&ch| ch != '.')
+        .collect::<Vec<_>>();
+    chars.sort_unstable();
+    chars.dedup();
+
+    grid.map(|ch| chars.binary_search(ch).map_or(0, |i| i + 1))
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+
+    out.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = u16::MAX as usize;
+
+    // Deflate with a 32K window, no preset dictionary, fastest compression
+    let mut res = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        res.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        res.push(u8::from(is_final));
+        res.extend(len.to_le_bytes());
+        res.extend((!len).to_le_bytes());
+        res.extend(block);
+    }
+
+    res.extend(adler32(data).to_be_bytes());
+
+    res
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb88320 & mask);
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+
+    let (a, b) = data.iter().fold((1_u32, 0_u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % MOD;
+        (a, (b + a) % MOD)
+    });
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_parse_palette() {
+        let palette = "#000000, #ff8800,#0000ff".parse::<Palette>().unwrap();
+
+        assert_eq!(palette.color(0), Rgb(0, 0, 0));
+        assert_eq!(palette.color(1), Rgb(0xff, 0x88, 0));
+        assert_eq!(palette.color(3), Rgb(0xff, 0x88, 0));
+        assert_eq!(palette.color(4).to_string(), "#0000ff");
+        assert!("#12345".parse::<Palette>().is_err());
+        assert!("red".parse::<Rgb>().is_err());
+    }
+
+    #[test]
+    fn test_from_grid_scales_cells() {
+        let grid = Grid::from_vec(1, 2, vec![0, 1]);
+
+        let image = Image::from_indices(&grid, 3, &Palette::default());
+
+        assert_eq!((image.width(), image.height()), (6, 3));
+        assert_eq!(image.pixel(2, 2), Palette::default().color(0));
+        assert_eq!(image.pixel(3, 0), Palette::default().color(1));
+    }
+
+    #[test]
+    fn test_char_indices() {
+        let grid = "#.@\n.#.".parse::<Grid<char>>().unwrap();
+
+        assert_eq!(
+            char_indices(&grid),
+            Grid::from_vec(2, 3, vec![1, 0, 2, 0, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let grid = Grid::from_vec(1, 2, vec![Rgb(1, 2, 3), Rgb(4, 5, 6)]);
+
+        let ppm = Image::from_grid(&grid, 1, |&c| c).to_ppm();
+
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x01\x02\x03\x04\x05\x06");
+    }
+
+    #[test]
+    fn test_to_png() {
+        let grid = Grid::from_vec(1, 1, vec![Rgb(255, 0, 0)]);
+
+        let png = Image::from_grid(&grid, 1, |&c| c).to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        // Scanline of filter byte 0 then one red pixel, in a single final stored block
+        let idat = png.windows(4).position(|w| w == b"IDAT").unwrap() + 4;
+        assert_eq!(
+            &png[idat..idat + 15],
+            &[0x78, 0x01, 1, 4, 0, 0xfb, 0xff, 0, 255, 0, 0, 0x03, 0x01, 0x01, 0x00]
+        );
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Where a day's puzzle input is read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A directory containing `dayN.txt` files
+    Dir(PathBuf),
+    /// A single input file, used as-is for whichever day is run
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the source from an explicit `--input` argument (where `-` means
+    /// stdin), falling back to `inputs_dir`, then `$AOC_INPUTS_DIR`, then `inputs/`.
+    pub fn resolve(input: Option<&Path>, inputs_dir: Option<&Path>) -> Self {
+        match input {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path.to_path_buf()),
+            None => InputSource::Dir(
+                inputs_dir
+                    .map(Path::to_path_buf)
+                    .or_else(|| std::env::var_os(INPUTS_DIR_ENV).map(PathBuf::from))
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR)),
+            ),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> anyhow::Result<String> {
+        match self {
+            InputSource::Dir(dir) => read_file(&dir.join(format!("day{}.txt", day)), day),
+            InputSource::File(path) => read_file(path, day),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read input from stdin")?;
+
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the input for reading a piece at a time, for inputs too big to
+    /// read up front.
+    pub fn open(&self, day: u8) -> anyhow::Result<Box<dyn Read>> {
+        match self {
+            InputSource::Dir(dir) => open_file(&dir.join(format!("day{}.txt", day)), day),
+            InputSource::File(path) => open_file(path, day),
+            InputSource::Stdin => Ok(Box::new(std::io::stdin())),
+        }
+    }
+}
+
+fn open_file(path: &Path, day: u8) -> anyhow::Result<Box<dyn Read>> {
+    check_exists(path, day)?;
+
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+
+    Ok(Box::new(file))
+}
+
+/// The error when a day's input file doesn't exist, so callers can tell a
+/// day that hasn't been downloaded yet from one that failed to read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingInput {
+    pub day: u8,
+    pub path: PathBuf,
+}
+
+impl Display for MissingInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No input for day {}: {} does not exist (use --input or --inputs-dir to point elsewhere)",
+            self.day,
+            self.path.display()
+        )
+    }
+}
+
+impl std::error::Error for MissingInput {}
+
+fn check_exists(path: &Path, day: u8) -> anyhow::Result<()> {
+    if !path.is_file() {
+        return Err(MissingInput {
+            day,
+            path: path.to_path_buf(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn read_file(path: &Path, day: u8) -> anyhow::Result<String> {
+    check_exists(path, day)?;
+
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            InputSource::resolve(Some(Path::new("-")), None),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve(Some(Path::new("day1.txt")), Some(Path::new("puzzles"))),
+            InputSource::File(PathBuf::from("day1.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve(None, Some(Path::new("puzzles"))),
+            InputSource::Dir(PathBuf::from("puzzles"))
+        );
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let source = InputSource::Dir(PathBuf::from("does-not-exist"));
+
+        let err = source.read(4).unwrap_err();
+
+        assert!(err.to_string().starts_with("No input for day 4"));
+        assert_eq!(
+            err.downcast_ref::<MissingInput>()
+                .map(|missing| missing.day),
+            Some(4)
+        );
+    }
+}
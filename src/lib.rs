@@ -0,0 +1,21 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod examples;
+pub mod explain;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod parse;
+pub mod regions;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod sort;
+pub mod sparse;
+pub mod stencil;
+pub mod visited;
+pub mod word_search;
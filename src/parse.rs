@@ -0,0 +1,174 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A parse failure pinned to a 1-based line and column of the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Shifts the error down by `lines`, for when the text that failed to parse
+    /// didn't start on the first line of the input.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.text.is_empty() {
+            write!(
+                f,
+                "line {}, column {}: {}, found end of line",
+                self.line, self.column, self.message
+            )
+        } else {
+            write!(
+                f,
+                "line {}, column {}: {}, found {:?}",
+                self.line, self.column, self.message, self.text
+            )
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Reads literals and numbers off the front of a single line, keeping track
+/// of the column so failures can point at the offending text.
+#[derive(Debug)]
+pub struct Scanner<'a> {
+    line: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Scanner { line, pos: 0 }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.rest().trim_end().is_empty()
+    }
+
+    pub fn column(&self) -> usize {
+        self.line[..self.pos].chars().count() + 1
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let token = self
+            .rest()
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        ParseError::new(1, self.column(), token, message)
+    }
+
+    pub fn expect(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {:?}", literal)))
+        }
+    }
+
+    /// Reads an optionally signed integer.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let rest = self.rest();
+        let sign_len = usize::from(rest.starts_with(['-', '+']));
+        let digits_len = rest[sign_len..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len() - sign_len);
+
+        if digits_len == 0 {
+            return Err(self.error("expected a number"));
+        }
+
+        let len = sign_len + digits_len;
+        let n = rest[..len]
+            .parse::<T>()
+            .map_err(|_| self.error("number out of range"))?;
+
+        self.pos += len;
+
+        Ok(n)
+    }
+
+    /// Fails if anything other than trailing whitespace is left on the line.
+    pub fn finish(&self) -> Result<(), ParseError> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected trailing text"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let mut scanner = Scanner::new("p=0,-4");
+
+        scanner.expect("p=").unwrap();
+        assert_eq!(scanner.number::<i32>(), Ok(0));
+        scanner.expect(",").unwrap();
+        assert_eq!(scanner.number::<i32>(), Ok(-4));
+        assert!(scanner.finish().is_ok());
+
+        // Indentation is part of the line, not something to skip
+        assert!(Scanner::new("  p=0,-4").expect("p=").is_err());
+    }
+
+    #[test]
+    fn test_scanner_error_position() {
+        let mut scanner = Scanner::new("p=0,x4 v=1,2");
+
+        scanner.expect("p=").unwrap();
+        scanner.number::<i32>().unwrap();
+        scanner.expect(",").unwrap();
+
+        let err = scanner.number::<i32>().unwrap_err().offset_lines(2);
+
+        assert_eq!(err, ParseError::new(3, 5, "x4", "expected a number"));
+        assert_eq!(
+            err.to_string(),
+            r#"line 3, column 5: expected a number, found "x4""#
+        );
+    }
+
+    #[test]
+    fn test_scanner_out_of_range() {
+        let err = Scanner::new("300").number::<u8>().unwrap_err();
+
+        assert_eq!(err.message, "number out of range");
+        assert_eq!(err.column, 1);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    search::Search,
+    visited::VisitedSet,
+};
+
+/// A connected group of orthogonally adjacent cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub label: usize,
+    /// The region's first cell in row-major order.
+    pub start: (usize, usize),
+    pub area: usize,
+    /// Number of cell edges between the region and anything outside it.
+    pub perimeter: usize,
+    /// Number of straight fence runs around the region, including the ones
+    /// around any holes.
+    pub sides: usize,
+    /// Top-left corner of the bounding box.
+    pub min: (usize, usize),
+    /// Bottom-right corner of the bounding box, inclusive.
+    pub max: (usize, usize),
+    pub touches_border: bool,
+}
+
+/// Every region of a grid, along with a map from each cell to its region's label.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Labels `grid` so that adjacent cells share a region whenever
+    /// `same_region` holds for them. Labels count up from zero in row-major
+    /// order of each region's first cell.
+    pub fn new<T, F>(grid: &Grid<T>, same_region: F) -> Self
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut labels = Grid::new(grid.nrows(), grid.ncols(), usize::MAX);
+        let mut starts = vec![];
+        // Regions never overlap, so one set serves every search without clearing
+        let mut seen = VisitedSet::for_grid(grid);
+
+        for pos in grid.positions() {
+            if labels[pos] != usize::MAX {
+                continue;
+            }
+
+            let label = starts.len();
+            let cells = Search::new(pos).bfs_with(&mut seen, |&cell| {
+                grid.neighbors(cell)
+                    .filter(|&neighbor| same_region(&grid[cell], &grid[neighbor]))
+                    .collect::<Vec<_>>()
+            });
+
+            for cell in cells {
+                labels[cell] = label;
+            }
+            starts.push(pos);
+        }
+
+        let mut regions = starts
+            .into_iter()
+            .enumerate()
+            .map(|(label, start)| Region {
+                label,
+                start,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                min: start,
+                max: start,
+                touches_border: false,
+            })
+            .collect::<Vec<_>>();
+
+        for (pos, &label) in labels.iter() {
+            let in_region = |delta: Point| {
+                labels
+                    .step(pos, delta)
+                    .is_some_and(|neighbor| labels[neighbor] == label)
+            };
+
+            let region = &mut regions[label];
+            region.area += 1;
+            region.min = (region.min.0.min(pos.0), region.min.1.min(pos.1));
+            region.max = (region.max.0.max(pos.0), region.max.1.max(pos.1));
+            region.touches_border |= pos.0 == 0
+                || pos.1 == 0
+                || pos.0 == labels.nrows() - 1
+                || pos.1 == labels.ncols() - 1;
+
+            for dir in Direction::ALL {
+                if !in_region(dir.delta()) {
+                    region.perimeter += 1;
+                }
+
+                // A polygon has as many sides as corners, so count the corner
+                // of this cell between `dir` and the direction clockwise of it
+                let side = dir.turn_right().delta();
+                let outer = !in_region(dir.delta()) && !in_region(side);
+                let inner =
+                    in_region(dir.delta()) && in_region(side) && !in_region(dir.delta() + side);
+                if outer || inner {
+                    region.sides += 1;
+                }
+            }
+        }
+
+        Regions { labels, regions }
+    }
+
+    /// The label of every cell.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn get(&self, label: usize) -> Option<&Region> {
+        self.regions.get(label)
+    }
+
+    pub fn region_at(&self, pos: (usize, usize)) -> &Region {
+        &self.regions[self.labels[pos]]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    /// Positions of every cell in the region labelled `label`, in row-major order.
+    pub fn cells(&self, label: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.labels
+            .iter()
+            .filter(move |(_, &l)| l == label)
+            .map(|(pos, _)| pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regions(s: &str) -> Regions {
+        Regions::new(&s.parse::<Grid<char>>().unwrap(), |a, b| a == b)
+    }
+
+    #[test]
+    fn test_regions_with_holes() {
+        let regions = regions(
+            "OOOOO\n\
+             OXOXO\n\
+             OOOOO\n\
+             OXOXO\n\
+             OOOOO",
+        );
+
+        assert_eq!(regions.len(), 5);
+
+        let outer = regions.region_at((0, 0));
+        assert_eq!(outer.area, 21);
+        assert_eq!(outer.perimeter, 36);
+        assert_eq!(outer.sides, 20);
+        assert_eq!((outer.min, outer.max), ((0, 0), (4, 4)));
+        assert!(outer.touches_border);
+
+        let hole = regions.region_at((3, 3));
+        assert_eq!(hole.label, 4);
+        assert_eq!(hole.start, (3, 3));
+        assert_eq!((hole.area, hole.perimeter, hole.sides), (1, 4, 4));
+        assert_eq!((hole.min, hole.max), ((3, 3), (3, 3)));
+        assert!(!hole.touches_border);
+    }
+
+    #[test]
+    fn test_sides_of_diagonally_touching_holes() {
+        let regions = regions(
+            "AAAAAA\n\
+             AAABBA\n\
+             AAABBA\n\
+             ABBAAA\n\
+             ABBAAA\n\
+             AAAAAA",
+        );
+
+        assert_eq!(regions.region_at((0, 0)).sides, 12);
+        assert_eq!(regions.region_at((1, 3)).sides, 4);
+        assert_ne!(
+            regions.region_at((1, 3)).label,
+            regions.region_at((3, 1)).label
+        );
+    }
+
+    #[test]
+    fn test_cells_and_bounding_box() {
+        let regions = regions(
+            "EEEEE\n\
+             EXXXX\n\
+             EEEEE",
+        );
+
+        let e = regions.region_at((0, 0));
+        assert_eq!(e.sides, 8);
+        assert_eq!(regions.cells(e.label).count(), 11);
+        assert_eq!(regions.region_at((1, 4)).min, (1, 1));
+        assert_eq!(regions.labels()[(1, 2)], 1);
+    }
+}
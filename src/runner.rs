@@ -0,0 +1,274 @@
+use std::{
+    io::{BufReader, Read},
+    time::{Duration, Instant},
+};
+
+use anyhow::bail;
+
+use crate::{days::Day, solution::Part};
+
+#[derive(Debug)]
+pub struct RunResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+    pub input_hash: u64,
+}
+
+pub fn run_part(day: &Day, part: Part, input: &str) -> anyhow::Result<RunResult> {
+    let start = Instant::now();
+    let answer = day.solution.solve(input, part)?;
+    let elapsed = start.elapsed();
+
+    Ok(RunResult {
+        day: day.number,
+        part,
+        answer,
+        elapsed,
+        input_hash: input_hash(input),
+    })
+}
+
+/// Solves both parts in one pass over `input`, for days that support it.
+/// The parts aren't solved separately, so part one reports the time of the
+/// whole pass and part two reports none, keeping totals right.
+pub fn run_streaming(day: &Day, input: impl Read) -> anyhow::Result<Vec<RunResult>> {
+    let mut input = BufReader::new(HashingReader {
+        inner: input,
+        hash: FNV_OFFSET_BASIS,
+    });
+
+    let start = Instant::now();
+    let Some(answers) = day.solution.solve_streaming(&mut input) else {
+        bail!("Day {} has no streaming mode", day.number);
+    };
+    let answers = answers?;
+    let elapsed = start.elapsed();
+
+    let input_hash = input.into_inner().hash;
+
+    Ok(Part::BOTH
+        .into_iter()
+        .zip(answers)
+        .map(|(part, answer)| RunResult {
+            day: day.number,
+            part,
+            answer,
+            elapsed: match part {
+                Part::One => elapsed,
+                Part::Two => Duration::ZERO,
+            },
+            input_hash,
+        })
+        .collect())
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// 64-bit FNV-1a of the puzzle input, so results from different inputs can be
+/// told apart without storing the input itself.
+pub fn input_hash(input: &str) -> u64 {
+    fnv1a(FNV_OFFSET_BASIS, input.as_bytes())
+}
+
+/// Hashes everything read through it, so streamed inputs get the same
+/// [`input_hash`] as inputs read up front.
+struct HashingReader<R> {
+    inner: R,
+    hash: u64,
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hash = fnv1a(self.hash, &buf[..n]);
+
+        Ok(n)
+    }
+}
+
+pub fn format_duration(elapsed: Duration) -> String {
+    let micros = elapsed.as_micros();
+
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    }
+}
+
+pub fn summary_table(results: &[RunResult]) -> String {
+    let answer_width = results
+        .iter()
+        .map(|r| r.answer.chars().count())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut res = format!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>10}\n",
+        "Day", "Part", "Answer", "Time"
+    );
+    res += &format!("{}\n", "-".repeat(3 + 2 + 4 + 2 + answer_width + 2 + 10));
+
+    for r in results {
+        res += &format!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}\n",
+            r.day,
+            r.part,
+            r.answer,
+            format_duration(r.elapsed)
+        );
+    }
+
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
+    res += &format!("\nTotal: {}\n", format_duration(total));
+
+    res
+}
+
+/// Renders one JSON object per line, with the elapsed time in nanoseconds.
+pub fn results_json_lines(results: &[RunResult]) -> String {
+    results
+        .iter()
+        .map(|r| {
+            format!(
+                r#"{{"day": {}, "part": {}, "answer": {}, "elapsed_ns": {}, "input_hash": "{:016x}"}}"#,
+                r.day,
+                r.part,
+                json_string(&r.answer),
+                r.elapsed.as_nanos(),
+                r.input_hash
+            ) + "\n"
+        })
+        .collect()
+}
+
+/// Renders results as CSV with a header row, with the elapsed time in nanoseconds.
+pub fn results_csv(results: &[RunResult]) -> String {
+    let mut res = "day,part,answer,elapsed_ns,input_hash\n".to_string();
+
+    for r in results {
+        res += &format!(
+            "{},{},{},{},{:016x}\n",
+            r.day,
+            r.part,
+            csv_field(&r.answer),
+            r.elapsed.as_nanos(),
+            r.input_hash
+        );
+    }
+
+    res
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut res = String::from('"');
+
+    for ch in s.chars() {
+        match ch {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            ch if ch.is_control() => res += &format!("\\u{:04x}", ch as u32),
+            ch => res.push(ch),
+        }
+    }
+
+    res.push('"');
+    res
+}
+
+pub(crate) fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(42)), "42µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+
+    #[test]
+    fn test_run_part() {
+        let day = crate::days::get(9).unwrap();
+
+        let res = run_part(day, Part::One, "2333133121414131402").unwrap();
+
+        assert_eq!(res.day, 9);
+        assert_eq!(res.part, Part::One);
+        assert_eq!(res.answer, "1928");
+    }
+
+    #[test]
+    fn test_run_streaming() {
+        let day = crate::days::get(1).unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+        let res = run_streaming(day, input.as_bytes()).unwrap();
+
+        assert_eq!(res[0].answer, "11");
+        assert_eq!(res[1].answer, "31");
+        assert_eq!(res[1].input_hash, input_hash(input));
+        assert!(run_streaming(crate::days::get(9).unwrap(), "".as_bytes()).is_err());
+    }
+
+    fn result(answer: &str) -> RunResult {
+        RunResult {
+            day: 3,
+            part: Part::Two,
+            answer: answer.to_string(),
+            elapsed: Duration::from_micros(42),
+            input_hash: input_hash("abc"),
+        }
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+    }
+
+    #[test]
+    fn test_results_json_lines() {
+        let res = results_json_lines(&[result("48"), result("say \"hi\"")]);
+
+        assert_eq!(
+            res,
+            concat!(
+                r#"{"day": 3, "part": 2, "answer": "48", "elapsed_ns": 42000, "input_hash": "e71fa2190541574b"}"#,
+                "\n",
+                r#"{"day": 3, "part": 2, "answer": "say \"hi\"", "elapsed_ns": 42000, "input_hash": "e71fa2190541574b"}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_results_csv() {
+        let res = results_csv(&[result("48"), result("4,6,3")]);
+
+        assert_eq!(
+            res,
+            "day,part,answer,elapsed_ns,input_hash\n3,2,48,42000,e71fa2190541574b\n3,2,\"4,6,3\",42000,e71fa2190541574b\n"
+        );
+    }
+}
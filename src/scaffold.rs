@@ -0,0 +1,216 @@
+use std::path::Path;
+
+use anyhow::{bail, ensure, Context};
+
+/// Paths are relative to the repository root, which `aoc new` must be run from.
+const DAYS_MOD: &str = "src/days/mod.rs";
+
+/// Everything `aoc new` writes for a day, as `(path, contents)` pairs.
+pub fn day_files(day: u8) -> Vec<(String, String)> {
+    vec![
+        (format!("src/days/day{}.rs", day), template(day)),
+        (format!("examples/day{}/example.txt", day), String::new()),
+        (
+            format!("examples/day{}/example.answers", day),
+            format!("[day{}]\n# part_one = \"\"\n# part_two = \"\"\n", day),
+        ),
+    ]
+}
+
+/// Creates the solution module, example fixtures and an empty input file for
+/// `day`, then registers the module with the runner. Returns the files written.
+pub fn new_day(day: u8) -> anyhow::Result<Vec<String>> {
+    ensure!((1..=25).contains(&day), "Day must be between 1 and 25");
+
+    let mod_rs = std::fs::read_to_string(DAYS_MOD)
+        .with_context(|| format!("Failed to read {}, run from the repository root", DAYS_MOD))?;
+    let registered = register(&mod_rs, day)?;
+
+    let mut files = day_files(day);
+    for (path, _) in &files {
+        ensure!(!Path::new(path).exists(), "{} already exists", path);
+    }
+
+    let input_path = format!("inputs/day{}.txt", day);
+    if !Path::new(&input_path).exists() {
+        files.push((input_path, String::new()));
+    }
+
+    for (path, contents) in &files {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path))?;
+    }
+
+    std::fs::write(DAYS_MOD, registered)
+        .with_context(|| format!("Failed to write {}", DAYS_MOD))?;
+
+    Ok(files
+        .into_iter()
+        .map(|(path, _)| path)
+        .chain(std::iter::once(DAYS_MOD.to_string()))
+        .collect())
+}
+
+/// Adds `pub mod dayN;` and a registry entry for `day` to the contents of
+/// `src/days/mod.rs`, keeping the module list in rustfmt's order.
+pub fn register(mod_rs: &str, day: u8) -> anyhow::Result<String> {
+    let name = format!("day{}", day);
+    let module = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .map(str::to_string)
+    };
+
+    let mut lines = mod_rs.lines().map(str::to_string).collect::<Vec<_>>();
+    if lines
+        .iter()
+        .any(|line| module(line).as_ref() == Some(&name))
+    {
+        bail!("Day {} is already registered", day);
+    }
+
+    let insert_at = lines
+        .iter()
+        .position(|line| module(line).is_some_and(|m| m > name))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| module(line).is_some())
+                .map(|i| i + 1)
+        })
+        .unwrap_or(0);
+    lines.insert(insert_at, format!("pub mod {};", name));
+
+    let days_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static DAYS"))
+        .context("No DAYS registry found")?;
+    let days_end = lines[days_start..]
+        .iter()
+        .position(|line| line == "];")
+        .map(|i| days_start + i)
+        .context("DAYS registry is not terminated")?;
+
+    let entry = [
+        "    Day {".to_string(),
+        format!("        number: {},", day),
+        format!("        solution: &day{0}::Day{0},", day),
+        "    },".to_string(),
+    ];
+    lines.splice(days_end..days_end, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+pub fn template(day: u8) -> String {
+    TEMPLATE.replace("{day}", &day.to_string())
+}
+
+const TEMPLATE: &str = r#"use crate::solution::Solution;
+
+fn part_one(_input: &str) -> usize {
+    todo!()
+}
+
+fn part_two(_input: &str) -> usize {
+    todo!()
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> usize {
+        part_two(input)
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+
+    const MOD_RS: &str = "pub mod day1;
+pub mod day10;
+pub mod day9;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &day1::Day1,
+    },
+];
+";
+
+    #[test]
+    fn test_register() {
+        let res = register(MOD_RS, 16).unwrap();
+
+        assert_eq!(
+            res,
+            "pub mod day1;
+pub mod day10;
+pub mod day16;
+pub mod day9;
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        solution: &day1::Day1,
+    },
+    Day {
+        number: 16,
+        solution: &day16::Day16,
+    },
+];
+"
+        );
+    }
+
+    #[test]
+    fn test_register_twice() {
+        assert!(register(MOD_RS, 10).is_err());
+    }
+
+    #[test]
+    fn test_register_current_days() {
+        let mod_rs = include_str!("days/mod.rs");
+
+        let res = register(mod_rs, 16).unwrap();
+
+        assert!(res.contains("pub mod day16;\n"));
+        assert!(res.contains("solution: &day16::Day16,"));
+    }
+
+    #[test]
+    fn test_template() {
+        let res = template(16);
+
+        assert!(res.contains("pub struct Day16;"));
+        assert!(res.contains("impl Solution for Day16 {"));
+        assert!(!res.contains("{day}"));
+    }
+
+    #[test]
+    fn test_example_answers_parse() {
+        let (_, answers) = &day_files(16)[2];
+
+        let answers = answers.parse::<Answers>().unwrap();
+
+        assert_eq!(answers.days().count(), 0);
+    }
+}
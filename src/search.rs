@@ -0,0 +1,538 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+type GoalFn<'a, N> = Box<dyn Fn(&N) -> bool + 'a>;
+
+/// Where a search marks the nodes it has reached. Passing one in lets a
+/// caller reuse it across searches, or use a denser set than a `HashSet`,
+/// like a [`VisitedSet`](crate::visited::VisitedSet) for grid positions.
+pub trait NodeSet<N> {
+    /// Marks `node`, returning whether it was new.
+    fn insert(&mut self, node: N) -> bool;
+}
+
+impl<N: Eq + Hash> NodeSet<N> for HashSet<N> {
+    fn insert(&mut self, node: N) -> bool {
+        HashSet::insert(self, node)
+    }
+}
+
+/// A search over an implicit graph, configured with its start nodes and
+/// optionally a goal and a cap on how many nodes may be visited.
+///
+/// Nodes can be anything hashable, from grid positions to `(position,
+/// direction)` states. Neighbors come from a closure, so the graph never has
+/// to be built up front:
+///
+/// ```
+/// use aoc_2024::search::Search;
+///
+/// let visited = Search::new(1_u32)
+///     .until(|&n| n == 10)
+///     .bfs(|&n| [n + 1, n * 2]);
+///
+/// assert_eq!(visited.goal_path(), Some(vec![1, 2, 4, 5, 10]));
+/// ```
+pub struct Search<'a, N> {
+    starts: Vec<N>,
+    is_goal: Option<GoalFn<'a, N>>,
+    max_visits: Option<usize>,
+}
+
+impl<'a, N> Search<'a, N>
+where
+    N: Clone + Eq + Hash,
+{
+    pub fn new(start: N) -> Self {
+        Search::from_starts([start])
+    }
+
+    /// Searches outward from every start at once, each at cost zero.
+    pub fn from_starts(starts: impl IntoIterator<Item = N>) -> Self {
+        Search {
+            starts: starts.into_iter().collect(),
+            is_goal: None,
+            max_visits: None,
+        }
+    }
+
+    /// Stops as soon as a node matching `is_goal` is visited.
+    pub fn until(mut self, is_goal: impl Fn(&N) -> bool + 'a) -> Self {
+        self.is_goal = Some(Box::new(is_goal));
+        self
+    }
+
+    /// Gives up after visiting `max_visits` nodes, marking the result as truncated.
+    pub fn max_visits(mut self, max_visits: usize) -> Self {
+        self.max_visits = Some(max_visits);
+        self
+    }
+
+    fn is_goal(&self, node: &N) -> bool {
+        self.is_goal.as_ref().is_some_and(|is_goal| is_goal(node))
+    }
+
+    fn limit_reached(&self, visits: usize) -> bool {
+        self.max_visits.is_some_and(|max| visits >= max)
+    }
+
+    /// Breadth-first search, where each node's cost is its number of steps
+    /// from the nearest start.
+    pub fn bfs<F, I>(&self, mut neighbors: F) -> Visited<N, usize>
+    where
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+    {
+        let mut visited = Visited::new(&self.starts, 0);
+        let mut queue = self.starts.iter().cloned().collect::<VecDeque<_>>();
+
+        while let Some(node) = queue.pop_front() {
+            if self.limit_reached(visited.order.len()) {
+                visited.truncated = true;
+                break;
+            }
+
+            visited.order.push(node.clone());
+            if self.is_goal(&node) {
+                visited.goal = Some(node);
+                break;
+            }
+
+            let cost = visited.nodes[&node].1 + 1;
+            for next in neighbors(&node) {
+                if let Entry::Vacant(entry) = visited.nodes.entry(next.clone()) {
+                    entry.insert((Some(node.clone()), cost));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Breadth-first search that only keeps the order nodes were visited in,
+    /// marking them in `seen` rather than recording costs and parents. Nodes
+    /// already in `seen` are treated as visited, so clear it between searches
+    /// that shouldn't share nodes. Stops after the goal or at the visit limit.
+    pub fn bfs_with<S, F, I>(&self, seen: &mut S, mut neighbors: F) -> Vec<N>
+    where
+        S: NodeSet<N>,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+    {
+        let mut order = vec![];
+        let mut queue = self
+            .starts
+            .iter()
+            .filter(|&start| seen.insert(start.clone()))
+            .cloned()
+            .collect::<VecDeque<_>>();
+
+        while let Some(node) = queue.pop_front() {
+            if self.limit_reached(order.len()) {
+                break;
+            }
+
+            order.push(node.clone());
+            if self.is_goal(&node) {
+                break;
+            }
+
+            queue.extend(
+                neighbors(&node)
+                    .into_iter()
+                    .filter(|next| seen.insert(next.clone())),
+            );
+        }
+
+        order
+    }
+
+    /// Depth-first search, where each node's cost is its depth in the search
+    /// tree rather than its distance from the start.
+    pub fn dfs<F, I>(&self, mut neighbors: F) -> Visited<N, usize>
+    where
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+    {
+        let mut visited = Visited::new(&[], 0);
+        let mut seen = HashSet::new();
+        let mut stack = self
+            .starts
+            .iter()
+            .rev()
+            .map(|start| (start.clone(), None, 0))
+            .collect::<Vec<_>>();
+
+        while let Some((node, parent, depth)) = stack.pop() {
+            if seen.contains(&node) {
+                continue;
+            }
+
+            if self.limit_reached(visited.order.len()) {
+                visited.truncated = true;
+                break;
+            }
+
+            seen.insert(node.clone());
+            visited.nodes.insert(node.clone(), (parent, depth));
+            visited.order.push(node.clone());
+            if self.is_goal(&node) {
+                visited.goal = Some(node);
+                break;
+            }
+
+            // Pushed in reverse so neighbors are visited in the order given
+            let mut next = neighbors(&node)
+                .into_iter()
+                .filter(|next| !seen.contains(next))
+                .collect::<Vec<_>>();
+            next.reverse();
+            stack.extend(
+                next.into_iter()
+                    .map(|next| (next, Some(node.clone()), depth + 1)),
+            );
+        }
+
+        visited
+    }
+
+    /// Lowest-cost search over non-negative edge costs.
+    pub fn dijkstra<C, F, I>(&self, neighbors: F) -> Visited<N, C>
+    where
+        C: Copy + Ord + Default + Add<Output = C>,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        self.astar(neighbors, |_| C::default())
+    }
+
+    /// Lowest-cost search guided by `heuristic`, an estimate of the remaining
+    /// cost to the goal. The heuristic must never overestimate and must be
+    /// consistent, or the costs found may not be the lowest.
+    pub fn astar<C, F, I, H>(&self, mut neighbors: F, mut heuristic: H) -> Visited<N, C>
+    where
+        C: Copy + Ord + Default + Add<Output = C>,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = (N, C)>,
+        H: FnMut(&N) -> C,
+    {
+        let mut visited = Visited::new(&self.starts, C::default());
+        let mut settled = HashSet::new();
+
+        // The heap holds indices into `pending` so nodes don't need to be `Ord`
+        let mut pending = vec![];
+        let mut heap = BinaryHeap::new();
+        for start in &self.starts {
+            heap.push(Reverse((heuristic(start), pending.len())));
+            pending.push(start.clone());
+        }
+
+        while let Some(Reverse((_, index))) = heap.pop() {
+            let node = pending[index].clone();
+            if settled.contains(&node) {
+                continue;
+            }
+
+            if self.limit_reached(visited.order.len()) {
+                visited.truncated = true;
+                break;
+            }
+
+            settled.insert(node.clone());
+            visited.order.push(node.clone());
+            if self.is_goal(&node) {
+                visited.goal = Some(node);
+                break;
+            }
+
+            let cost = visited.nodes[&node].1;
+            for (next, step_cost) in neighbors(&node) {
+                if settled.contains(&next) {
+                    continue;
+                }
+
+                let next_cost = cost + step_cost;
+                let improved = match visited.nodes.get(&next) {
+                    Some(&(_, known)) => next_cost < known,
+                    None => true,
+                };
+
+                if improved {
+                    visited
+                        .nodes
+                        .insert(next.clone(), (Some(node.clone()), next_cost));
+                    heap.push(Reverse((next_cost + heuristic(&next), pending.len())));
+                    pending.push(next);
+                }
+            }
+        }
+
+        // Costs of nodes that were never settled may not be the lowest
+        visited.nodes.retain(|node, _| settled.contains(node));
+
+        visited
+    }
+}
+
+/// The outcome of a search: every node reached, with its cost and the node it
+/// was reached from.
+#[derive(Debug, Clone)]
+pub struct Visited<N, C> {
+    nodes: HashMap<N, (Option<N>, C)>,
+    order: Vec<N>,
+    goal: Option<N>,
+    truncated: bool,
+}
+
+impl<N, C> Visited<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn new(starts: &[N], zero: C) -> Self {
+        Visited {
+            nodes: starts
+                .iter()
+                .map(|start| (start.clone(), (None, zero)))
+                .collect(),
+            order: vec![],
+            goal: None,
+            truncated: false,
+        }
+    }
+
+    /// Nodes in the order they were visited.
+    pub fn order(&self) -> &[N] {
+        &self.order
+    }
+
+    /// Whether `node` was reached. After a breadth-first search this includes
+    /// nodes queued but not yet visited when the search stopped.
+    pub fn contains(&self, node: &N) -> bool {
+        self.nodes.contains_key(node)
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.nodes.get(node).map(|&(_, cost)| cost)
+    }
+
+    /// The nodes from a start to `node`, inclusive.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut current = self.nodes.get(node)?;
+
+        while let Some(parent) = &current.0 {
+            path.push(parent.clone());
+            current = &self.nodes[parent];
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// The first goal visited, if the search had one and found it.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// Whether the search stopped at its visit limit before finishing.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+/// Counts the distinct paths from `start` to any node matching `is_goal`. The
+/// graph must be acyclic; a path stops at the first goal it reaches.
+pub fn count_paths<N, F, I, G>(start: N, mut neighbors: F, mut is_goal: G) -> usize
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    fn count<N, F, I, G>(
+        node: &N,
+        neighbors: &mut F,
+        is_goal: &mut G,
+        memo: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+        G: FnMut(&N) -> bool,
+    {
+        if is_goal(node) {
+            return 1;
+        }
+        if let Some(&paths) = memo.get(node) {
+            return paths;
+        }
+
+        let paths = neighbors(node)
+            .into_iter()
+            .collect::<Vec<_>>()
+            .iter()
+            .map(|next| count(next, neighbors, is_goal, memo))
+            .sum();
+
+        memo.insert(node.clone(), paths);
+        paths
+    }
+
+    count(&start, &mut neighbors, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn maze() -> Grid<char> {
+        "S.#.\n\
+         ..#.\n\
+         .#..\n\
+         ...E"
+            .parse()
+            .unwrap()
+    }
+
+    fn open_neighbors(grid: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbors(pos).filter(|&n| grid[n] != '#').collect()
+    }
+
+    #[test]
+    fn test_bfs_shortest_path() {
+        let grid = maze();
+
+        let visited = Search::new((0, 0))
+            .until(|&pos| grid[pos] == 'E')
+            .bfs(|&pos| open_neighbors(&grid, pos));
+
+        assert_eq!(visited.goal(), Some(&(3, 3)));
+        assert_eq!(visited.goal_cost(), Some(6));
+        assert_eq!(visited.goal_path().unwrap().len(), 7);
+        assert!(!visited.is_truncated());
+    }
+
+    #[test]
+    fn test_bfs_visits_everything_without_goal() {
+        let grid = maze();
+
+        let visited = Search::new((0, 0)).bfs(|&pos| open_neighbors(&grid, pos));
+
+        assert_eq!(visited.order().len(), 13);
+        assert_eq!(visited.cost(&(0, 3)), Some(9));
+    }
+
+    #[test]
+    fn test_bfs_with_shared_set() {
+        let grid = maze();
+        let mut seen = HashSet::new();
+
+        let order = Search::new((0, 0)).bfs_with(&mut seen, |&pos| open_neighbors(&grid, pos));
+        assert_eq!(
+            order,
+            Search::new((0, 0))
+                .bfs(|&pos| open_neighbors(&grid, pos))
+                .order()
+        );
+
+        // Everything reachable is already marked, so a second search finds nothing
+        let again = Search::new((3, 3)).bfs_with(&mut seen, |&pos| open_neighbors(&grid, pos));
+        assert!(again.is_empty());
+    }
+
+    #[test]
+    fn test_dfs() {
+        let grid = maze();
+
+        let visited = Search::new((0, 0))
+            .until(|&pos| pos == (0, 3))
+            .dfs(|&pos| open_neighbors(&grid, pos));
+
+        let path = visited.goal_path().unwrap();
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(0, 3)));
+        assert!(path
+            .windows(2)
+            .all(|w| grid.neighbors(w[0]).any(|n| n == w[1])));
+    }
+
+    #[test]
+    fn test_max_visits() {
+        let visited = Search::new(0_u64).max_visits(5).bfs(|&n| [n + 1]);
+
+        assert!(visited.is_truncated());
+        assert_eq!(visited.order(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_dijkstra_prefers_cheaper_longer_path() {
+        // 0 -> 3 directly costs 10, via 1 and 2 costs 3
+        let edges = HashMap::from([
+            (0, vec![(1, 1), (3, 10)]),
+            (1, vec![(2, 1)]),
+            (2, vec![(3, 1)]),
+            (3, vec![]),
+        ]);
+
+        let visited = Search::new(0)
+            .until(|&n| n == 3)
+            .dijkstra(|n| edges[n].clone());
+
+        assert_eq!(visited.goal_cost(), Some(3));
+        assert_eq!(visited.goal_path(), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid = maze();
+        let end = (3, 3);
+        let weighted = |&pos: &(usize, usize)| {
+            open_neighbors(&grid, pos)
+                .into_iter()
+                .map(|n| (n, 1 + n.0))
+                .collect::<Vec<_>>()
+        };
+
+        let dijkstra = Search::new((0, 0)).until(|&p| p == end).dijkstra(weighted);
+        let astar = Search::new((0, 0))
+            .until(|&p| p == end)
+            .astar(weighted, |&(row, col)| {
+                end.0.abs_diff(row) + end.1.abs_diff(col)
+            });
+
+        assert_eq!(astar.goal_cost(), dijkstra.goal_cost());
+        assert!(astar.order().len() <= dijkstra.order().len());
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Every monotone path through a 3x3 grid
+        let paths = count_paths(
+            (0, 0),
+            |&(row, col)| {
+                [(row + 1, col), (row, col + 1)]
+                    .into_iter()
+                    .filter(|&(r, c)| r < 3 && c < 3)
+            },
+            |&pos| pos == (2, 2),
+        );
+
+        assert_eq!(paths, 6);
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    io::BufRead,
+    time::{Duration, Instant},
+};
+
+use crate::{animate::Frames, explain::Explanation, grid::Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.number(), f)
+    }
+}
+
+/// The answer for a part that hasn't been solved yet, reported by the runner
+/// as `unsolved` instead of panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Unsolved {
+    pub const ANSWER: &'static str = "unsolved";
+}
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(Unsolved::ANSWER)
+    }
+}
+
+/// A day's solver. The implementing type doubles as the day's config, so days
+/// that need extra parameters (like day14's room size) carry them as fields.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(&self, input: &Self::Input) -> Self::PartTwo;
+
+    /// A picture of the puzzle for `aoc render`, as palette indices with 0 for
+    /// the background. Days with nothing to show beyond their input map
+    /// leave this as `None`.
+    fn render(&self, _input: &Self::Input) -> Option<Grid<usize>> {
+        None
+    }
+
+    /// The simulation behind `part`, one frame per tick, for `--animate`.
+    /// Days that don't simulate anything leave this as `None`.
+    fn animate(&self, _input: Self::Input, _part: Part) -> Option<Frames> {
+        None
+    }
+
+    /// A per-item breakdown of the answers for `aoc explain`, for days where
+    /// it's useful to audit how each item was judged. Days whose answers are
+    /// a single computation leave this as `None`.
+    fn explain(&self, _input: &Self::Input) -> Option<Explanation> {
+        None
+    }
+
+    /// Solves both parts in a single pass over `input` without holding it all
+    /// in memory, for `--stream`. Days whose inputs are always small leave
+    /// this as `None`.
+    fn solve_streaming(
+        &self,
+        _input: &mut dyn BufRead,
+    ) -> Option<anyhow::Result<(Self::PartOne, Self::PartTwo)>> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], used by the registry so every day can
+/// be driven through the same interface regardless of its input and answer types.
+pub trait DynSolution: Sync {
+    fn solve(&self, input: &str, part: Part) -> anyhow::Result<String>;
+
+    /// Runs parse, part one and part two once, timing each phase separately.
+    fn time_phases(&self, input: &str) -> anyhow::Result<PhaseTimings>;
+
+    fn render(&self, input: &str) -> anyhow::Result<Option<Grid<usize>>>;
+
+    fn animate(&self, input: &str, part: Part) -> anyhow::Result<Option<Frames>>;
+
+    fn explain(&self, input: &str) -> anyhow::Result<Option<Explanation>>;
+
+    /// Both answers from a single pass over `input`, if the day supports it.
+    fn solve_streaming(&self, input: &mut dyn BufRead) -> Option<anyhow::Result<[String; 2]>>;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseTimings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+{
+    fn solve(&self, input: &str, part: Part) -> anyhow::Result<String> {
+        let parsed = self.parse(input)?;
+
+        Ok(match part {
+            Part::One => self.part_one(&parsed).to_string(),
+            Part::Two => self.part_two(&parsed).to_string(),
+        })
+    }
+
+    fn time_phases(&self, input: &str) -> anyhow::Result<PhaseTimings> {
+        let start = Instant::now();
+        let parsed = self.parse(black_box(input))?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part_one(&parsed));
+        let part_one = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part_two(&parsed));
+        let part_two = start.elapsed();
+
+        Ok(PhaseTimings {
+            parse,
+            part_one,
+            part_two,
+        })
+    }
+
+    fn render(&self, input: &str) -> anyhow::Result<Option<Grid<usize>>> {
+        Ok(Solution::render(self, &self.parse(input)?))
+    }
+
+    fn animate(&self, input: &str, part: Part) -> anyhow::Result<Option<Frames>> {
+        Ok(Solution::animate(self, self.parse(input)?, part))
+    }
+
+    fn explain(&self, input: &str) -> anyhow::Result<Option<Explanation>> {
+        Ok(Solution::explain(self, &self.parse(input)?))
+    }
+
+    fn solve_streaming(&self, input: &mut dyn BufRead) -> Option<anyhow::Result<[String; 2]>> {
+        let answers = Solution::solve_streaming(self, input)?;
+
+        Some(answers.map(|(one, two)| [one.to_string(), two.to_string()]))
+    }
+}
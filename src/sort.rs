@@ -0,0 +1,317 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Sorts `values` with a least-significant-digit radix sort, one byte per
+/// pass. Passes where every value has the same byte are skipped, so small
+/// numbers only pay for the bytes they use.
+pub fn radix_sort(values: &mut [u64]) {
+    let mut buffer = vec![0; values.len()];
+
+    for shift in (0..64).step_by(8) {
+        let byte = |value: u64| (value >> shift) as usize & 0xff;
+
+        let mut counts = [0; 256];
+        for &value in values.iter() {
+            counts[byte(value)] += 1;
+        }
+        if counts.contains(&values.len()) {
+            continue;
+        }
+
+        let mut offsets = [0; 256];
+        for i in 1..256 {
+            offsets[i] = offsets[i - 1] + counts[i - 1];
+        }
+
+        for &value in values.iter() {
+            let slot = &mut offsets[byte(value)];
+            buffer[*slot] = value;
+            *slot += 1;
+        }
+
+        values.copy_from_slice(&buffer);
+    }
+}
+
+/// The most runs merged at once, which bounds how many files a merge keeps
+/// open.
+const MAX_FAN_IN: usize = 64;
+
+/// Sorts more numbers than fit in memory by radix sorting `chunk_size` of them
+/// at a time, spilling each sorted run to a temporary file and merging the
+/// runs back together at the end. Inputs that fit in a single chunk never
+/// touch the disk.
+///
+/// Runs are closed once written, and at most `MAX_FAN_IN` (64) are open while
+/// merging: with more runs than that, groups of them are first merged into
+/// longer runs, as many passes as it takes.
+#[derive(Debug)]
+pub struct ExternalSorter {
+    chunk: Vec<u64>,
+    chunk_size: usize,
+    runs: Vec<Run>,
+}
+
+impl ExternalSorter {
+    pub fn new(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "Chunk size must be at least 1");
+
+        ExternalSorter {
+            chunk: vec![],
+            chunk_size,
+            runs: vec![],
+        }
+    }
+
+    pub fn push(&mut self, value: u64) -> io::Result<()> {
+        self.chunk.push(value);
+        if self.chunk.len() == self.chunk_size {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        radix_sort(&mut self.chunk);
+        self.runs
+            .push(Run::write(self.chunk.iter().map(|&value| Ok(value)))?);
+        self.chunk.clear();
+
+        Ok(())
+    }
+
+    /// Every value pushed so far, in ascending order.
+    pub fn finish(mut self) -> io::Result<Sorted> {
+        if self.runs.is_empty() {
+            radix_sort(&mut self.chunk);
+
+            return Ok(Sorted {
+                chunk: self.chunk.into_iter(),
+                merge: None,
+            });
+        }
+
+        if !self.chunk.is_empty() {
+            self.spill()?;
+        }
+
+        let mut runs = self.runs;
+        while runs.len() > MAX_FAN_IN {
+            let mut remaining = runs.into_iter();
+            runs = vec![];
+
+            loop {
+                let group = remaining.by_ref().take(MAX_FAN_IN).collect::<Vec<_>>();
+                if group.is_empty() {
+                    break;
+                }
+
+                // Merging drops the group's runs, deleting their files
+                runs.push(Run::write(Merge::new(group)?)?);
+            }
+        }
+
+        Ok(Sorted {
+            chunk: vec![].into_iter(),
+            merge: Some(Merge::new(runs)?),
+        })
+    }
+}
+
+/// The output of an [`ExternalSorter`], merged lazily from its runs.
+#[derive(Debug)]
+pub struct Sorted {
+    chunk: std::vec::IntoIter<u64>,
+    merge: Option<Merge>,
+}
+
+impl Iterator for Sorted {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.merge {
+            Some(merge) => merge.next(),
+            None => self.chunk.next().map(Ok),
+        }
+    }
+}
+
+/// A k-way merge of sorted runs, which holds each run open until it is
+/// dropped.
+#[derive(Debug)]
+struct Merge {
+    readers: Vec<RunReader>,
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+    // Dropped after the readers, so files are closed before they're deleted
+    _runs: Vec<Run>,
+}
+
+impl Merge {
+    fn new(runs: Vec<Run>) -> io::Result<Self> {
+        let mut readers = runs.iter().map(Run::open).collect::<io::Result<Vec<_>>>()?;
+
+        let mut heap = BinaryHeap::new();
+        for (i, reader) in readers.iter_mut().enumerate() {
+            if let Some(value) = reader.next()? {
+                heap.push(Reverse((value, i)));
+            }
+        }
+
+        Ok(Merge {
+            readers,
+            heap,
+            _runs: runs,
+        })
+    }
+}
+
+impl Iterator for Merge {
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((value, i)) = self.heap.pop()?;
+        match self.readers[i].next() {
+            Ok(Some(next)) => self.heap.push(Reverse((next, i))),
+            Ok(None) => {}
+            Err(e) => return Some(Err(e)),
+        }
+
+        Some(Ok(value))
+    }
+}
+
+/// A sorted run of little-endian `u64`s in a temporary file, which is deleted
+/// once the run is dropped. The file is only open while being written or
+/// read through a [`RunReader`].
+#[derive(Debug)]
+struct Run {
+    path: PathBuf,
+}
+
+impl Run {
+    fn write(values: impl IntoIterator<Item = io::Result<u64>>) -> io::Result<Self> {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "aoc-sort-{}-{}.bin",
+            std::process::id(),
+            NEXT_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let file = File::options().write(true).create_new(true).open(&path)?;
+        // From here on the file is cleaned up by `Drop`, even if writing fails
+        let run = Run { path };
+
+        let mut writer = BufWriter::new(file);
+        for value in values {
+            writer.write_all(&value?.to_le_bytes())?;
+        }
+        writer.flush()?;
+
+        Ok(run)
+    }
+
+    fn open(&self) -> io::Result<RunReader> {
+        Ok(RunReader(BufReader::new(File::open(&self.path)?)))
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[derive(Debug)]
+struct RunReader(BufReader<File>);
+
+impl RunReader {
+    fn next(&mut self) -> io::Result<Option<u64>> {
+        let mut bytes = [0; 8];
+
+        match self.0.read_exact(&mut bytes) {
+            Ok(()) => Ok(Some(u64::from_le_bytes(bytes))),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pseudo_random(n: usize) -> Vec<u64> {
+        let mut state = 0x2545f4914f6cdd1d_u64;
+
+        (0..n)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % 100_000
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_radix_sort() {
+        let mut values = pseudo_random(1000);
+        values.extend([u64::MAX, 0, 1 << 40]);
+        let mut expected = values.clone();
+        expected.sort_unstable();
+
+        radix_sort(&mut values);
+
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_external_sort_merges_runs() {
+        let values = pseudo_random(1001);
+        let mut expected = values.clone();
+        expected.sort_unstable();
+
+        for chunk_size in [7, 64, 5000] {
+            let mut sorter = ExternalSorter::new(chunk_size);
+            for &value in &values {
+                sorter.push(value).unwrap();
+            }
+
+            let sorted = sorter
+                .finish()
+                .unwrap()
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+
+            assert_eq!(sorted, expected, "chunk size {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn test_external_sort_merges_in_passes() {
+        let values = pseudo_random(10_000);
+        let mut expected = values.clone();
+        expected.sort_unstable();
+
+        // Far more runs than can be merged at once, so some are merged twice
+        let chunk_size = 2;
+        assert!(values.len() / chunk_size > MAX_FAN_IN * MAX_FAN_IN);
+
+        let mut sorter = ExternalSorter::new(chunk_size);
+        for &value in &values {
+            sorter.push(value).unwrap();
+        }
+        let sorted = sorter.finish().unwrap();
+
+        assert!(sorted.merge.as_ref().unwrap().readers.len() <= MAX_FAN_IN);
+        assert_eq!(sorted.collect::<io::Result<Vec<_>>>().unwrap(), expected);
+    }
+}
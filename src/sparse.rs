@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use crate::{geometry::Point, grid::Grid};
+
+/// An unbounded grid that only stores occupied cells, addressed by signed
+/// [`Point`]s. A cell can hold any number of occupants, like several robots
+/// sharing a tile.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, Vec<T>>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Adds `occupant` to the cell at `pos`, alongside any already there.
+    pub fn insert(&mut self, pos: Point, occupant: T) {
+        self.cells.entry(pos).or_default().push(occupant);
+
+        self.bounds = Some(extend(self.bounds, pos));
+    }
+
+    /// Empties the cell at `pos`, returning whatever occupied it.
+    pub fn remove(&mut self, pos: Point) -> Vec<T> {
+        let Some(occupants) = self.cells.remove(&pos) else {
+            return vec![];
+        };
+
+        // Only a cell on the edge of the bounding box can shrink it
+        if let Some((min, max)) = self.bounds {
+            if pos.row == min.row || pos.row == max.row || pos.col == min.col || pos.col == max.col
+            {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &pos| Some(extend(bounds, pos)));
+            }
+        }
+
+        occupants
+    }
+
+    /// Everything at `pos`, which is empty for unoccupied cells.
+    pub fn get(&self, pos: Point) -> &[T] {
+        self.cells.get(&pos).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Number of occupants at `pos`.
+    pub fn count(&self, pos: Point) -> usize {
+        self.get(pos).len()
+    }
+
+    /// Number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every occupied cell with its occupants, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &[T])> {
+        self.cells
+            .iter()
+            .map(|(&pos, occupants)| (pos, occupants.as_slice()))
+    }
+
+    /// The top-left and bottom-right (inclusive) corners of the smallest
+    /// rectangle holding every occupied cell, or `None` when there are none.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Copies the occupied extent into a dense grid, calling `f` with the
+    /// occupants of each cell (an empty slice for unoccupied ones).
+    pub fn to_grid<U, F>(&self, mut f: F) -> Option<Grid<U>>
+    where
+        F: FnMut(&[T]) -> U,
+    {
+        let (min, max) = self.bounds?;
+        let nrows = (max.row - min.row + 1) as usize;
+        let ncols = (max.col - min.col + 1) as usize;
+
+        Some(Grid::from_fn(nrows, ncols, |pos| {
+            f(self.get(min + Point::from(pos)))
+        }))
+    }
+
+    /// Renders the occupied extent one line per row, using `to_char` for the
+    /// occupants of each cell. An empty grid renders as an empty string.
+    pub fn display_with<F>(&self, to_char: F) -> String
+    where
+        F: FnMut(&[T]) -> char,
+    {
+        self.to_grid(to_char)
+            .map(|grid| grid.to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (pos, occupant) in iter {
+            grid.insert(pos, occupant);
+        }
+
+        grid
+    }
+}
+
+/// The smallest bounding box holding both `bounds` and `pos`.
+fn extend(bounds: Option<(Point, Point)>, pos: Point) -> (Point, Point) {
+    match bounds {
+        Some((min, max)) => (
+            Point::new(min.row.min(pos.row), min.col.min(pos.col)),
+            Point::new(max.row.max(pos.row), max.col.max(pos.col)),
+        ),
+        None => (pos, pos),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiple_occupants_and_bounds() {
+        let mut grid = [(-2_isize, 3_isize), (1, -1), (1, -1)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, pos)| (Point::from(pos), i))
+            .collect::<SparseGrid<_>>();
+
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(Point::new(1, -1)), &[1, 2]);
+        assert_eq!(grid.count(Point::new(0, 0)), 0);
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(1, 3))));
+
+        assert_eq!(grid.remove(Point::new(-2, 3)), vec![0]);
+        assert_eq!(grid.bounds(), Some((Point::new(1, -1), Point::new(1, -1))));
+        assert_eq!(grid.remove(Point::new(1, -1)), vec![1, 2]);
+        assert_eq!(grid.bounds(), None);
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_display_occupied_extent() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::new(-1, -1), 'a');
+        grid.insert(Point::new(0, 1), 'b');
+        grid.insert(Point::new(0, 1), 'c');
+
+        let display = grid.display_with(|occupants| match occupants.len() {
+            0 => '.',
+            n => char::from_digit(n as u32, 10).unwrap(),
+        });
+
+        assert_eq!(display, "1..\n..2\n");
+        assert_eq!(SparseGrid::<()>::new().display_with(|_| '.'), "");
+    }
+}
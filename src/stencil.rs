@@ -0,0 +1,160 @@
+use crate::grid::Grid;
+
+/// Where a stencil matched: the top-left corner of the matching window and
+/// which of the stencil's orientations matched there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StencilMatch {
+    pub origin: (usize, usize),
+    pub variant: usize,
+}
+
+/// A small 2D template of literal cells and wildcards, along with any extra
+/// orientations it should also match in.
+///
+/// ```
+/// use aoc_2024::{grid::Grid, stencil::Stencil};
+///
+/// let grid = "AB.\nBAA\n.AB".parse::<Grid<char>>().unwrap();
+/// let diagonal = Stencil::parse("A?\n?A", '?').unwrap();
+///
+/// assert_eq!(diagonal.count(&grid), 1);
+/// assert_eq!(diagonal.rotated().count(&grid), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stencil<T> {
+    variants: Vec<Grid<Option<T>>>,
+}
+
+impl<T> Stencil<T>
+where
+    T: Clone + PartialEq,
+{
+    /// A stencil matching `cells` as is, where `None` matches anything.
+    pub fn new(cells: Grid<Option<T>>) -> Self {
+        Stencil {
+            variants: vec![cells],
+        }
+    }
+
+    /// Also matches every quarter turn of each orientation so far.
+    pub fn rotated(self) -> Self {
+        self.with_variants(|cells| {
+            let once = cells.rotate_right();
+            let twice = once.rotate_right();
+            let thrice = twice.rotate_right();
+
+            vec![once, twice, thrice]
+        })
+    }
+
+    /// Also matches the mirror image of each orientation so far. Together
+    /// with [`Stencil::rotated`] this covers all eight symmetries of a square.
+    pub fn reflected(self) -> Self {
+        self.with_variants(|cells| vec![cells.flip_horizontal()])
+    }
+
+    /// Adds the orientations `f` makes from each existing one, skipping
+    /// duplicates so symmetric templates don't match twice in the same place.
+    fn with_variants<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Grid<Option<T>>) -> Vec<Grid<Option<T>>>,
+    {
+        for cells in self.variants.clone() {
+            for variant in f(&cells) {
+                if !self.variants.contains(&variant) {
+                    self.variants.push(variant);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Every distinct orientation, starting with the original template.
+    pub fn variants(&self) -> &[Grid<Option<T>>] {
+        &self.variants
+    }
+
+    /// Every match in `grid`, in row-major order of origin, then by variant.
+    pub fn find(&self, grid: &Grid<T>) -> Vec<StencilMatch> {
+        let mut res = vec![];
+
+        for (variant, cells) in self.variants.iter().enumerate() {
+            for window in grid.windows(cells.nrows(), cells.ncols()) {
+                let matches = cells.iter().all(|(pos, cell)| {
+                    cell.as_ref()
+                        .is_none_or(|expected| window[pos] == *expected)
+                });
+
+                if matches {
+                    res.push(StencilMatch {
+                        origin: window.origin(),
+                        variant,
+                    });
+                }
+            }
+        }
+
+        res.sort_unstable_by_key(|m| (m.origin, m.variant));
+
+        res
+    }
+
+    pub fn count(&self, grid: &Grid<T>) -> usize {
+        self.find(grid).len()
+    }
+}
+
+impl Stencil<char> {
+    /// Parses a template one row per line, with `wildcard` matching any cell.
+    pub fn parse(s: &str, wildcard: char) -> anyhow::Result<Self> {
+        let cells = Grid::parse_with(s, |ch| Ok((ch != wildcard).then_some(ch)))?;
+
+        Ok(Stencil::new(cells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "#..#\n\
+         ##.#\n\
+         ..#.\n\
+         .##."
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_find_with_wildcards() {
+        let corner = Stencil::parse("#.\n##", '?').unwrap();
+        let loose = Stencil::parse("#?\n##", '?').unwrap();
+
+        assert_eq!(
+            corner.find(&grid()),
+            vec![StencilMatch {
+                origin: (0, 0),
+                variant: 0
+            }]
+        );
+        assert_eq!(loose.count(&grid()), 1);
+        assert_eq!(Stencil::parse("??\n??", '?').unwrap().count(&grid()), 9);
+    }
+
+    #[test]
+    fn test_rotations_and_reflections() {
+        let corner = Stencil::parse("#.\n##", '?').unwrap();
+        assert_eq!(corner.clone().rotated().variants().len(), 4);
+        assert_eq!(corner.clone().rotated().count(&grid()), 2);
+
+        let bar = Stencil::parse("##", '?').unwrap();
+        assert_eq!(bar.clone().rotated().variants().len(), 2);
+        assert_eq!(bar.rotated().reflected().count(&grid()), 5);
+
+        let hook = Stencil::parse("#..\n###", '?').unwrap();
+        assert_eq!(hook.clone().reflected().variants().len(), 2);
+        assert_eq!(hook.rotated().reflected().variants().len(), 8);
+    }
+}
@@ -0,0 +1,190 @@
+use std::marker::PhantomData;
+
+use crate::{geometry::Direction, grid::Grid, search::NodeSet};
+
+/// Something a [`VisitedSet`] can track: a grid position, optionally paired
+/// with a direction.
+pub trait GridState: Copy {
+    /// How many states share each position.
+    const PER_CELL: usize;
+
+    fn pos(&self) -> (usize, usize);
+
+    /// Which of the position's `PER_CELL` states this is.
+    fn slot(&self) -> usize;
+}
+
+impl GridState for (usize, usize) {
+    const PER_CELL: usize = 1;
+
+    fn pos(&self) -> (usize, usize) {
+        *self
+    }
+
+    fn slot(&self) -> usize {
+        0
+    }
+}
+
+impl GridState for ((usize, usize), Direction) {
+    const PER_CELL: usize = 4;
+
+    fn pos(&self) -> (usize, usize) {
+        self.0
+    }
+
+    fn slot(&self) -> usize {
+        match self.1 {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+        }
+    }
+}
+
+/// A set of states on an `nrows` by `ncols` grid, stored as one bit each.
+///
+/// Clearing only resets the words that were written since the last clear, so
+/// one set can be reused across many searches on a big grid without paying
+/// for the whole grid each time.
+#[derive(Debug, Clone)]
+pub struct VisitedSet<S> {
+    nrows: usize,
+    ncols: usize,
+    words: Vec<u64>,
+    dirty: Vec<usize>,
+    len: usize,
+    state: PhantomData<S>,
+}
+
+impl<S: GridState> VisitedSet<S> {
+    pub fn new(nrows: usize, ncols: usize) -> Self {
+        let bits = nrows * ncols * S::PER_CELL;
+
+        VisitedSet {
+            nrows,
+            ncols,
+            words: vec![0; bits.div_ceil(64)],
+            dirty: vec![],
+            len: 0,
+            state: PhantomData,
+        }
+    }
+
+    /// An empty set sized to fit `grid`.
+    pub fn for_grid<T>(grid: &Grid<T>) -> Self {
+        VisitedSet::new(grid.nrows(), grid.ncols())
+    }
+
+    fn bit(&self, state: S) -> (usize, u64) {
+        let (row, col) = state.pos();
+        assert!(
+            row < self.nrows && col < self.ncols,
+            "Position {:?} is out of bounds for a {}x{} grid",
+            (row, col),
+            self.nrows,
+            self.ncols
+        );
+
+        let bit = (row * self.ncols + col) * S::PER_CELL + state.slot();
+
+        (bit / 64, 1 << (bit % 64))
+    }
+
+    /// Marks `state`, returning whether it was new.
+    pub fn insert(&mut self, state: S) -> bool {
+        let (word, mask) = self.bit(state);
+
+        if self.words[word] & mask != 0 {
+            return false;
+        }
+
+        if self.words[word] == 0 {
+            self.dirty.push(word);
+        }
+        self.words[word] |= mask;
+        self.len += 1;
+
+        true
+    }
+
+    pub fn contains(&self, state: S) -> bool {
+        let (word, mask) = self.bit(state);
+
+        self.words[word] & mask != 0
+    }
+
+    /// Number of states marked.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        for word in self.dirty.drain(..) {
+            self.words[word] = 0;
+        }
+        self.len = 0;
+    }
+}
+
+impl<S: GridState> NodeSet<S> for VisitedSet<S> {
+    fn insert(&mut self, state: S) -> bool {
+        VisitedSet::insert(self, state)
+    }
+}
+
+impl<S: GridState> Extend<S> for VisitedSet<S> {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for state in iter {
+            self.insert(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_contains_and_clear() {
+        let mut visited = VisitedSet::new(10, 10);
+
+        assert!(visited.insert((3, 4)));
+        assert!(!visited.insert((3, 4)));
+        assert!(visited.insert((9, 9)));
+        assert!(visited.contains((3, 4)));
+        assert!(!visited.contains((4, 3)));
+        assert_eq!(visited.len(), 2);
+
+        visited.clear();
+        assert!(visited.is_empty());
+        assert!(!visited.contains((3, 4)));
+        assert!(visited.insert((3, 4)));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_row_out_of_bounds() {
+        // 3x3 fits in one word with bits to spare, so this would have set padding
+        let mut visited = VisitedSet::new(3, 3);
+
+        visited.insert((3, 0));
+    }
+
+    #[test]
+    fn test_directions_are_separate() {
+        let mut visited = VisitedSet::new(2, 3);
+
+        visited.extend(Direction::ALL.map(|dir| ((1, 2), dir)));
+        visited.insert(((1, 2), Direction::Up));
+
+        assert_eq!(visited.len(), 4);
+        assert!(visited.contains(((1, 2), Direction::Left)));
+        assert!(!visited.contains(((0, 0), Direction::Left)));
+    }
+}
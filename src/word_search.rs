@@ -0,0 +1,185 @@
+use crate::{geometry::Direction8, grid::Grid};
+
+/// One occurrence of a word, read from `start` going in `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a> {
+    pub word: &'a str,
+    pub start: (usize, usize),
+    pub direction: Direction8,
+}
+
+impl Match<'_> {
+    /// The positions of the match's letters, first letter first.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let start = self.start;
+        let delta = self.direction.delta();
+
+        (0..self.word.chars().count()).map(move |i| {
+            let row = start.0 as isize + delta.row * i as isize;
+            let col = start.1 as isize + delta.col * i as isize;
+
+            (row as usize, col as usize)
+        })
+    }
+}
+
+/// Finds words in a grid of letters, reading in straight lines in any of the
+/// eight directions unless told otherwise.
+///
+/// ```
+/// use aoc_2024::{grid::Grid, word_search::WordFinder};
+///
+/// let grid = "CAT\nXOX\nDOG".parse::<Grid<char>>().unwrap();
+/// let matches = WordFinder::new(["C?T", "GOD"]).wildcard('?').find(&grid);
+///
+/// assert_eq!(matches.len(), 2);
+/// assert_eq!(matches[1].start, (2, 2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WordFinder<'a> {
+    words: Vec<&'a str>,
+    wildcard: Option<char>,
+    directions: Vec<Direction8>,
+}
+
+impl<'a> WordFinder<'a> {
+    /// Panics if any word is empty, since it would match everywhere.
+    pub fn new(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let words = words.into_iter().collect::<Vec<_>>();
+        assert!(
+            words.iter().all(|word| !word.is_empty()),
+            "Words to find can't be empty"
+        );
+
+        WordFinder {
+            words,
+            wildcard: None,
+            directions: Direction8::ALL.to_vec(),
+        }
+    }
+
+    /// Lets `wildcard` in a word stand for any letter.
+    pub fn wildcard(mut self, wildcard: char) -> Self {
+        self.wildcard = Some(wildcard);
+        self
+    }
+
+    /// Only reads words in `directions`.
+    pub fn directions(mut self, directions: impl IntoIterator<Item = Direction8>) -> Self {
+        self.directions = directions.into_iter().collect();
+        self
+    }
+
+    fn spells(&self, grid: &Grid<char>, word: &str, pos: (usize, usize), dir: Direction8) -> bool {
+        word.chars().enumerate().all(|(i, ch)| {
+            grid.step(pos, dir.delta() * i as isize)
+                .is_some_and(|pos| Some(ch) == self.wildcard || grid[pos] == ch)
+        })
+    }
+
+    /// Every match in row-major order of its start, then by direction, then
+    /// by the order the words were given.
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Match<'a>> {
+        let mut res = vec![];
+
+        for pos in grid.positions() {
+            for &direction in &self.directions {
+                for &word in &self.words {
+                    if self.spells(grid, word, pos, direction) {
+                        res.push(Match {
+                            word,
+                            start: pos,
+                            direction,
+                        });
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.find(grid).len()
+    }
+}
+
+/// A copy of `grid` keeping only the letters that are part of some match,
+/// with everything else replaced by `blank`.
+pub fn highlight(grid: &Grid<char>, matches: &[Match], blank: char) -> Grid<char> {
+    let mut res = grid.map(|_| blank);
+
+    for pos in matches.iter().flat_map(Match::cells) {
+        res[pos] = grid[pos];
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "SAMX\n\
+         XMAS\n\
+         AXAM"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_find_in_every_direction() {
+        let matches = WordFinder::new(["XMAS"]).find(&grid());
+
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    word: "XMAS",
+                    start: (0, 3),
+                    direction: Direction8::West
+                },
+                Match {
+                    word: "XMAS",
+                    start: (1, 0),
+                    direction: Direction8::East
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wildcards_and_directions() {
+        let finder = WordFinder::new(["X?A", "SA"]).wildcard('?');
+
+        assert_eq!(finder.count(&grid()), 6);
+        assert_eq!(
+            finder
+                .clone()
+                .directions([Direction8::South, Direction8::West])
+                .find(&grid())
+                .iter()
+                .map(|m| (m.word, m.start))
+                .collect::<Vec<_>>(),
+            vec![("X?A", (0, 3)), ("SA", (1, 3))]
+        );
+    }
+
+    #[test]
+    fn test_highlight() {
+        let grid = grid();
+        let matches = WordFinder::new(["AXA"]).find(&grid);
+
+        assert_eq!(
+            highlight(&grid, &matches, '.').to_string(),
+            "....\n....\nAXA.\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be empty")]
+    fn test_empty_word() {
+        WordFinder::new(["XMAS", ""]);
+    }
+}